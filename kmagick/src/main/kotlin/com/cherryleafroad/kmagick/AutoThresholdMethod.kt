@@ -0,0 +1,26 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The method [MagickWand.autoThresholdImage] uses to pick the threshold.
+ */
+enum class AutoThresholdMethod(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedThresholdMethod(0),
+
+    /**
+     * Kapur's maximum entropy method.
+     */
+    KapurThresholdMethod(1),
+
+    /**
+     * Otsu's method, which minimizes the intra-class variance.
+     */
+    OTSUThresholdMethod(2),
+
+    /**
+     * The triangle method, which works well for histograms with one strong peak.
+     */
+    TriangleThresholdMethod(3)
+}
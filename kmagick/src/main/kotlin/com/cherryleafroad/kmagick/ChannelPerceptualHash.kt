@@ -0,0 +1,18 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The perceptual hash of a single channel of an image, from `MagickWand.getImagePerceptualHash()`
+ */
+data class ChannelPerceptualHash(
+    /**
+     * The channel this hash belongs to. [PixelChannel.CompositePixelChannel] holds the hash of all the channels
+     * combined.
+     */
+    val channel: PixelChannel,
+
+    /**
+     * The image moments that make up the hash. All the moments of the first hash colorspace (sRGB by default) are
+     * followed by all the moments of the next one (HCLp by default).
+     */
+    val hash: DoubleArray
+)
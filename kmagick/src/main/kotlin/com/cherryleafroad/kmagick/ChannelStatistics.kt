@@ -0,0 +1,62 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The statistics of a single channel of an image, from `MagickWand.getImageStatistics()`
+ */
+data class ChannelStatistics(
+    /**
+     * The channel these statistics belong to. [PixelChannel.CompositePixelChannel] holds the statistics of all the
+     * channels combined.
+     */
+    val channel: PixelChannel,
+
+    /**
+     * The channel depth.
+     */
+    val depth: Long,
+
+    /**
+     * The number of pixels the statistics were computed over.
+     */
+    val area: Double,
+
+    /**
+     * The minimum value of the channel.
+     */
+    val minima: Double,
+
+    /**
+     * The maximum value of the channel.
+     */
+    val maxima: Double,
+
+    /**
+     * The mean value of the channel.
+     */
+    val mean: Double,
+
+    /**
+     * The standard deviation of the channel.
+     */
+    val standardDeviation: Double,
+
+    /**
+     * The variance of the channel.
+     */
+    val variance: Double,
+
+    /**
+     * The kurtosis of the channel.
+     */
+    val kurtosis: Double,
+
+    /**
+     * The skewness of the channel.
+     */
+    val skewness: Double,
+
+    /**
+     * The entropy of the channel.
+     */
+    val entropy: Double
+)
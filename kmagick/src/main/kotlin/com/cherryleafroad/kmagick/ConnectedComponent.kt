@@ -0,0 +1,36 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * A single object found by `MagickWand.connectedComponentsImage()`
+ */
+data class ConnectedComponent(
+    /**
+     * The object id (its label in the labeled image).
+     */
+    val id: Long,
+
+    /**
+     * The bounding box of the object.
+     */
+    val boundingBox: PageGeometry,
+
+    /**
+     * The x of the object's centroid.
+     */
+    val centroidX: Double,
+
+    /**
+     * The y of the object's centroid.
+     */
+    val centroidY: Double,
+
+    /**
+     * The area of the object, in pixels.
+     */
+    val area: Double,
+
+    /**
+     * The mean color of the object.
+     */
+    val color: PixelWand
+)
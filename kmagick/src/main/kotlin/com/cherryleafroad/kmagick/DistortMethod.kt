@@ -0,0 +1,108 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The distortion method used by [MagickWand.distortImage]. The meaning (and the number) of the distortion arguments
+ * depends on which method is used.
+ */
+enum class DistortMethod(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedDistortion(0),
+
+    /**
+     * Distort the image linearly by moving a list of at least 3 or more sets of control points.
+     * Ideally 3 sets or 12 floating point values are given allowing the image to be linearly scaled, rotated, sheared,
+     * and translated, according to those three points.
+     */
+    AffineDistortion(1),
+
+    /**
+     * Linearly distort an image using the given Affine Matrix of 6 pre-calculated coefficients forming a set of Affine
+     * Equations to map the source image to the destination image.
+     */
+    AffineProjectionDistortion(2),
+
+    /**
+     * Distort image by first scaling and rotating about a given 'center', before translating that 'center' to the new
+     * location, in that order.
+     */
+    ScaleRotateTranslateDistortion(3),
+
+    /**
+     * Perspective distort the images, using a list of 4 or more sets of control points.
+     */
+    PerspectiveDistortion(4),
+
+    /**
+     * Do a Perspective distortion using the set of 8 coefficients.
+     */
+    PerspectiveProjectionDistortion(5),
+
+    /**
+     * Bilinear Distortion, given a minimum of 4 sets of coordinate pairs, or 16 values.
+     */
+    BilinearForwardDistortion(6),
+
+    /**
+     * Same as [BilinearForwardDistortion].
+     */
+    BilinearDistortion(6),
+
+    /**
+     * The reverse form of [BilinearForwardDistortion].
+     */
+    BilinearReverseDistortion(7),
+
+    /**
+     * Distort the image using a polynomial of the given order, using a list of control points.
+     */
+    PolynomialDistortion(8),
+
+    /**
+     * Arc the image (variation of polar mapping) over the angle given around a circle.
+     */
+    ArcDistortion(9),
+
+    /**
+     * Like [ArcDistortion] but do a complete Cartesian to Polar mapping of the image. that is the height of the input
+     * image is mapped to the radius limits, while the width is wrapped around between the angle limits.
+     */
+    PolarDistortion(10),
+
+    /**
+     * Reverse the action of [PolarDistortion] mapping.
+     */
+    DePolarDistortion(11),
+
+    /**
+     * Convert a cylinder-shaped image (e.g. a panorama) to a flat plane.
+     */
+    Cylinder2PlaneDistortion(12),
+
+    /**
+     * Convert a flat image to a cylinder shape.
+     */
+    Plane2CylinderDistortion(13),
+
+    /**
+     * Corrects some barrel or pincushion effects present in lens distortions of photographs.
+     */
+    BarrelDistortion(14),
+
+    /**
+     * The inverse of [BarrelDistortion], using a slightly different radial equation.
+     */
+    BarrelInverseDistortion(15),
+
+    /**
+     * Distort the given list control points (any number) using an Inverse Squared Distance Interpolation Method
+     * (Shepards Method).
+     */
+    ShepardsDistortion(16),
+
+    /**
+     * Resize the image using the distortion engine (the same as using a scale-only [AffineDistortion]).
+     */
+    ResizeDistortion(17)
+}
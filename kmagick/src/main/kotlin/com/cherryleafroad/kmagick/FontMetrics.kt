@@ -0,0 +1,71 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The metrics of a piece of text, from `MagickWand.queryFontMetrics()` and `MagickWand.queryMultilineFontMetrics()`
+ */
+data class FontMetrics(
+    /**
+     * The character width.
+     */
+    val characterWidth: Double,
+
+    /**
+     * The character height.
+     */
+    val characterHeight: Double,
+
+    /**
+     * The ascender, the distance from the baseline to the top of the highest glyph.
+     */
+    val ascender: Double,
+
+    /**
+     * The descender, the distance from the baseline to the bottom of the lowest glyph. This is usually negative.
+     */
+    val descender: Double,
+
+    /**
+     * The text width.
+     */
+    val textWidth: Double,
+
+    /**
+     * The text height.
+     */
+    val textHeight: Double,
+
+    /**
+     * The maximum horizontal advance.
+     */
+    val maxHorizontalAdvance: Double,
+
+    /**
+     * The x1 of the bounding box.
+     */
+    val x1: Double,
+
+    /**
+     * The y1 of the bounding box.
+     */
+    val y1: Double,
+
+    /**
+     * The x2 of the bounding box.
+     */
+    val x2: Double,
+
+    /**
+     * The y2 of the bounding box.
+     */
+    val y2: Double,
+
+    /**
+     * The x of the origin.
+     */
+    val originX: Double,
+
+    /**
+     * The y of the origin.
+     */
+    val originY: Double
+)
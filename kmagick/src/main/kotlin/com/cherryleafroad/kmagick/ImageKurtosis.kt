@@ -0,0 +1,16 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The kurtosis and skewness of the image channels, from `MagickWand.getImageKurtosis()`
+ */
+data class ImageKurtosis(
+    /**
+     * The kurtosis.
+     */
+    val kurtosis: Double,
+
+    /**
+     * The skewness.
+     */
+    val skewness: Double
+)
@@ -0,0 +1,16 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The mean and standard deviation of the image channels, from `MagickWand.getImageMean()`
+ */
+data class ImageMean(
+    /**
+     * The mean pixel value.
+     */
+    val mean: Double,
+
+    /**
+     * The standard deviation.
+     */
+    val standardDeviation: Double
+)
@@ -0,0 +1,16 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The range of the image channels, from `MagickWand.getImageRange()`
+ */
+data class ImageRange(
+    /**
+     * The minimum pixel value.
+     */
+    val minima: Double,
+
+    /**
+     * The maximum pixel value.
+     */
+    val maxima: Double
+)
@@ -0,0 +1,175 @@
+package com.cherryleafroad.kmagick
+
+import java.io.Closeable
+
+/**
+ * The exception that all [KernelInfo]'s throw if there's an error.
+ */
+class KernelInfoException(message: String) : MagickException(message)
+
+/**
+ * [KernelInfo] API. A convolution / morphology kernel, used with [MagickWand.morphologyImage],
+ * [MagickWand.convolveImage] and [MagickWand.colorMatrixImage].
+ */
+@Suppress("unused", "MemberVisibilityCanBePrivate")
+class KernelInfo : Closeable {
+    /**
+     * Create a kernel from an ImageMagick kernel string, e.g. `"Disk:3"`, `"Diamond"`, `"Sobel:90"` or a user
+     * defined kernel such as `"3x3: 0,-1,0 -1,5,-1 0,-1,0"`.
+     *
+     * &nbsp;
+     *
+     * Multiple kernels can be given by separating them with a `;`.
+     */
+    constructor(kernel: String) {
+        new(kernel)
+    }
+
+    /**
+     * Create a user defined kernel of [width] x [height] from [values] (given row by row). A value of
+     * [Double.NaN] marks that element as not part of the kernel.
+     */
+    constructor(width: Long, height: Long, values: DoubleArray) {
+        newFromArray(width, height, values)
+    }
+
+    /**
+     * Internal use ONLY. Copies another kernel
+     */
+    internal constructor(kernel: KernelInfo) {
+        clone(kernel)
+    }
+
+    companion object {
+        /**
+         * Destroys all [KernelInfo]'s
+         *
+         * WARNING: DO NOT use the destroyed kernels after. They are invalidated after that.
+         */
+        fun destroyKernels() {
+            Magick.destroyWandType(WandType.KernelInfo.id)
+        }
+
+        /**
+         * Destroys all [KernelInfo]'s that match ids.
+         *
+         * WARNING: DO NOT use the destroyed kernels after. They are invalidated after that.
+         */
+        @OptIn(ExperimentalUnsignedTypes::class)
+        fun destroyKernelIds(ids: ULongArray) {
+            Magick.destroyWandIdsType(ids, WandType.KernelInfo.id)
+        }
+
+        /**
+         * Destroys a [KernelInfo] with a certain id.
+         *
+         * WARNING: DO NOT use the destroyed kernel after. It is invalidated after that.
+         */
+        fun destroyKernelId(id: ULong) {
+            Magick.destroyWandIdType(id, WandType.KernelInfo.id)
+        }
+    }
+
+    /**
+     * Holds the pointer to internal object in memory.
+     */
+    private var handle: Long? = null
+
+    /**
+     * The unique id of the kernel.
+     *
+     * &nbsp;
+     *
+     * This id is guaranteed to be unique amongst ALL wands of ALL types, kernels included
+     * (unless you overflow a [ULong], then it'll wrap back around)
+     */
+    val id: ULong
+        get() = _id
+    private var _id: ULong = 0u
+
+    /**
+     * Check to see if this is initialized with the underlying C obj.
+     *
+     * &nbsp;
+     *
+     * If it's not, then calling any functions will result in a `null` exception.
+     *
+     * &nbsp;
+     *
+     * This object is _ALWAYS_ initialized, unless a destroy method, or [Magick.terminate] got called.
+     */
+    val isInitialized: Boolean
+        get() = handle != null
+
+    /**
+     * Call the internal function to create the new kernel.
+     */
+    @Throws(KernelInfoException::class)
+    private external fun new(kernel: String)
+
+    /**
+     * Call the internal function to create the new kernel from an array.
+     */
+    @Throws(KernelInfoException::class)
+    private external fun newFromArray(width: Long, height: Long, values: DoubleArray)
+
+    /**
+     * Clone the kernel into a new one.
+     */
+    @Throws(KernelInfoException::class)
+    fun clone(): KernelInfo {
+        handle ?: throw KernelInfoException("Kernel is null")
+        return KernelInfo(this)
+    }
+    @Throws(KernelInfoException::class)
+    private external fun clone(kernel: KernelInfo)
+
+    /**
+     * It's recommended to manually destroy all kernels when finished.
+     * Otherwise the memory will stay around forever until [Magick.terminate]
+     */
+    external fun destroy()
+
+    /**
+     * This isn't meant to be called manually. You can call [destroy] instead. This does the
+     * same thing as [destroy], but it's here to be used with a `use{}` block for
+     * convenience. For example `kernel.use { }`
+     */
+    override fun close() {
+        destroy()
+    }
+
+    /**
+     * The width of the (first) kernel.
+     */
+    val width: Long
+        get() = kernelGetWidth()
+    @Throws(KernelInfoException::class)
+    private external fun kernelGetWidth(): Long
+
+    /**
+     * The height of the (first) kernel.
+     */
+    val height: Long
+        get() = kernelGetHeight()
+    @Throws(KernelInfoException::class)
+    private external fun kernelGetHeight(): Long
+
+    /**
+     * Scales all the values of the kernel by the given [scale] factor.
+     *
+     * @param scale The factor to multiply all the kernel values by.
+     * @param normalize Normalize the kernel (so the values add up to 1.0) before it is scaled.
+     */
+    @Throws(KernelInfoException::class)
+    external fun scaleKernelInfo(scale: Double, normalize: Boolean)
+
+    /**
+     * Scales the kernel using a geometry string, the same as the `-define convolve:scale` option,
+     * e.g. `"2!"` or `"50%,100%"`.
+     *
+     * @param geometry The scaling geometry.
+     */
+    @Throws(KernelInfoException::class)
+    external fun scaleGeometryKernelInfo(geometry: String)
+}
@@ -0,0 +1,91 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The method used to merge, compare or optimize the layers (frames) of an image sequence.
+ */
+enum class LayerMethod(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedLayer(0),
+
+    /**
+     * Fully define the look of each frame of a GIF animation sequence, to form a 'film strip' animation.
+     */
+    CoalesceLayer(1),
+
+    /**
+     * Crop each frame to the smallest bounds that contains all the pixels that changed from the previous frame.
+     */
+    CompareAnyLayer(2),
+
+    /**
+     * Crop each frame to the bounds of the pixels that were cleared (made transparent) from the previous frame.
+     */
+    CompareClearLayer(3),
+
+    /**
+     * Crop each frame to the bounds of the pixels that were added (overlaid) onto the previous frame.
+     */
+    CompareOverlayLayer(4),
+
+    /**
+     * Apply the GIF disposal methods set in the current image sequence to form the next frame.
+     */
+    DisposeLayer(5),
+
+    /**
+     * Optimize a coalesced animation, removing frames and transparency that aren't needed.
+     */
+    OptimizeLayer(6),
+
+    /**
+     * Optimize the coalesced frames by using the frame bounds of the images that actually changed.
+     */
+    OptimizeImageLayer(7),
+
+    /**
+     * As [OptimizeImageLayer], but also adds extra frames to improve the optimization of the whole animation.
+     */
+    OptimizePlusLayer(8),
+
+    /**
+     * Replace overlaid pixels that don't change with transparency, to improve compression.
+     */
+    OptimizeTransLayer(9),
+
+    /**
+     * Remove duplicate frames that follow each other, merging their time delays.
+     */
+    RemoveDupsLayer(10),
+
+    /**
+     * Remove any frame that has a zero time delay.
+     */
+    RemoveZeroLayer(11),
+
+    /**
+     * Alpha composite two image lists together.
+     */
+    CompositeLayer(12),
+
+    /**
+     * Merge all layers onto a canvas just large enough to hold all the layers, offsets are preserved.
+     */
+    MergeLayer(13),
+
+    /**
+     * Merge all layers onto a canvas the size of the first image's virtual canvas.
+     */
+    FlattenLayer(14),
+
+    /**
+     * Merge all layers onto a canvas that starts at (0, 0) and is large enough to hold all the layers.
+     */
+    MosaicLayer(15),
+
+    /**
+     * Find the bounds of all the layers, and set all the page offsets relative to them.
+     */
+    TrimBoundsLayer(16)
+}
@@ -217,6 +217,60 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun magickSetImageChannelMask(mask: Int): ChannelType
 
+    /**
+     * Separates a channel from the image and returns it as a new grayscale wand. This wand is left unchanged.
+     *
+     * @param channel The channel to separate, e.g. [ChannelType.RedChannel].
+     */
+    @Throws(MagickWandException::class)
+    fun separateImage(channel: ChannelType): MagickWand {
+        return magickSeparateImage(channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickSeparateImage(channel: Int): MagickWand
+
+    /**
+     * Combines one or more images into a single image. The grayscale value of the pixels of each image in the sequence
+     * is assigned in order to the specified channels of the combined image. The typical ordering would be image 1 =>
+     * Red, 2 => Green, 3 => Blue, etc. Returns the combined image as a new wand.
+     *
+     * @param colorspace The colorspace of the combined image, e.g. [ColorspaceType.sRGBColorspace].
+     */
+    @Throws(MagickWandException::class)
+    fun combineImages(colorspace: ColorspaceType): MagickWand {
+        return magickCombineImages(colorspace.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickCombineImages(colorspace: Int): MagickWand
+
+    /**
+     * Applies a channel expression to the image, and returns the result as a new wand. The expression consists of one
+     * or more channels, either mnemonic or numeric (e.g. red, 1), separated by actions as follows:
+     *
+     * &nbsp;
+     *
+     * `<=>` exchange two channels (e.g. `red<=>blue`)
+     *
+     * `=>` copy one channel to another channel (e.g. `red=>green`)
+     *
+     * `=` assign a constant value to a channel (e.g. `red=50%`)
+     *
+     * `,` write new image channels in the specified order (e.g. `red, green`)
+     *
+     * `;` add a new output image for the next set of channel operations (e.g. `red; green; blue`)
+     *
+     * `|` move to the next input image for the source of channel data (e.g. `| gray=>alpha`)
+     *
+     * &nbsp;
+     *
+     * For example, to create 3 grayscale images from the red, green, and blue channels of an image, use
+     * `"red; green; blue"`.
+     *
+     * @param expression The channel expression.
+     */
+    @Throws(MagickWandException::class)
+    external fun channelFxImage(expression: String): MagickWand
+
     /**
      * Applies an arithmetic, relational, or logical expression to an image. Use these operators to lighten or darken an
      * image, to increase or decrease contrast in an image, or to produce the "negative" of an image.
@@ -231,6 +285,59 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun magickEvaluateImage(op: Int, value: Double)
 
+    /**
+     * Applies an arithmetic, relational, or logical expression across all the images in the wand, pixel by pixel, and
+     * returns the single resulting image as a new wand. For example [EvaluateOperator.Mean] averages all the frames,
+     * and [EvaluateOperator.Median], [EvaluateOperator.Min] and [EvaluateOperator.Max] take the median, minimum and
+     * maximum of them.
+     *
+     * @param op The operator to apply across the images.
+     */
+    @Throws(MagickWandException::class)
+    fun evaluateImages(op: EvaluateOperator): MagickWand {
+        return magickEvaluateImages(op.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickEvaluateImages(op: Int): MagickWand
+
+    /**
+     * Returns a new wand where each pixel is the sum of the pixels in the image sequence after applying its
+     * corresponding terms (coefficient and degree pairs). This wand is left unchanged.
+     *
+     * &nbsp;
+     *
+     * For example, `doubleArrayOf(0.5, 1.0, 0.5, 1.0)` blends 2 frames equally, and
+     * `doubleArrayOf(1.0, 2.0, 0.0, 0.0)` squares the first frame and ignores the second.
+     *
+     * @param terms The coefficient and degree of each image in the sequence, so 2 values per image.
+     */
+    @Throws(MagickWandException::class)
+    external fun polynomialImage(terms: DoubleArray): MagickWand
+
+    /**
+     * Implements the discrete Fourier transform (DFT) of the current image either as a magnitude / phase or real /
+     * imaginary image pair. Returns a new wand holding the 2 resulting images, this wand is left unchanged.
+     *
+     * &nbsp;
+     *
+     * ImageMagick must be built with FFTW support for this to work, and with HDRI to preserve the precision needed for
+     * an exact round trip through [inverseFourierTransformImage].
+     *
+     * @param magnitude If `true`, return as magnitude / phase pair otherwise a real / imaginary image pair.
+     */
+    @Throws(MagickWandException::class)
+    external fun forwardFourierTransformImage(magnitude: Boolean): MagickWand
+
+    /**
+     * Implements the inverse discrete Fourier transform (DFT) of the image either as a magnitude / phase or real /
+     * imaginary image pair. This wand must hold the pair (such as the result of [forwardFourierTransformImage]), first
+     * the magnitude (or real) image and then the phase (or imaginary) image. Returns the rebuilt image as a new wand.
+     *
+     * @param magnitude If `true`, the pair is a magnitude / phase pair, otherwise a real / imaginary image pair.
+     */
+    @Throws(MagickWandException::class)
+    external fun inverseFourierTransformImage(magnitude: Boolean): MagickWand
+
     /**
      * Surrounds the image with a border of the color defined by the bordercolor pixel wand.
      */
@@ -241,6 +348,93 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun magickBorderImage(pixelWand: PixelWand, width: Long, height: Long, compose: Int)
 
+    /**
+     * Adds a simulated three-dimensional border around the image. The width and height specify the border width of the
+     * vertical and horizontal sides of the frame. The inner and outer bevels indicate the width of the inner and outer
+     * shadows of the frame.
+     *
+     * @param matteColor The frame color.
+     * @param width The border width.
+     * @param height The border height.
+     * @param innerBevel The inner bevel width.
+     * @param outerBevel The outer bevel width.
+     * @param compose The [CompositeOperator].
+     */
+    @Throws(MagickWandException::class)
+    fun frameImage(
+        matteColor: PixelWand,
+        width: Long,
+        height: Long,
+        innerBevel: Long,
+        outerBevel: Long,
+        compose: CompositeOperator
+    ) {
+        magickFrameImage(matteColor, width, height, innerBevel, outerBevel, compose.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickFrameImage(
+        matteColor: PixelWand,
+        width: Long,
+        height: Long,
+        innerBevel: Long,
+        outerBevel: Long,
+        compose: Int
+    )
+
+    /**
+     * Creates a simulated three-dimensional button-like effect by lightening and darkening the edges of the image.
+     * [width] and [height] define the width of the vertical and horizontal edge of the effect.
+     *
+     * @param width The width of the edge.
+     * @param height The height of the edge.
+     * @param x The x offset of the effect.
+     * @param y The y offset of the effect.
+     * @param raise `true` creates a 3-D raise effect, otherwise it has a lowered effect.
+     */
+    @Throws(MagickWandException::class)
+    external fun raiseImage(width: Long, height: Long, x: Long, y: Long, raise: Boolean)
+
+    /**
+     * Removes a region of the image and collapses the image to occupy the removed portion.
+     *
+     * @param width The region width.
+     * @param height The region height.
+     * @param x The region x offset.
+     * @param y The region y offset.
+     */
+    @Throws(MagickWandException::class)
+    external fun chopImage(width: Long, height: Long, x: Long, y: Long)
+
+    /**
+     * Splices a solid color into the image.
+     *
+     * @param pixelWand The color of the spliced region.
+     * @param width The region width.
+     * @param height The region height.
+     * @param x The region x offset.
+     * @param y The region y offset.
+     */
+    @Throws(MagickWandException::class)
+    external fun spliceImage(pixelWand: PixelWand, width: Long, height: Long, x: Long, y: Long)
+
+    /**
+     * Shaves pixels from the image edges.
+     *
+     * @param columns The number of columns to shave from the left and right edges.
+     * @param rows The number of rows to shave from the top and bottom edges.
+     */
+    @Throws(MagickWandException::class)
+    external fun shaveImage(columns: Long, rows: Long)
+
+    /**
+     * Offsets an image as defined by [x] and [y]. Pixels that are moved off one edge wrap around to the opposite edge.
+     *
+     * @param x The x offset.
+     * @param y The y offset.
+     */
+    @Throws(MagickWandException::class)
+    external fun rollImage(x: Long, y: Long)
+
     /**
      * Simulates an image shadow.
      *
@@ -255,19 +449,114 @@ class MagickWand : Closeable {
     /**
      * Sets the next image in the wand as the current image.
      *
-     * It is typically used after MagickResetIterator(), after which its first use will set the first image as the
+     * It is typically used after [setFirstIterator], after which its first use will set the first image as the
      * current image (unless the wand is empty).
      *
-     * It will return MagickFalse when no more images are left to be returned which happens when the wand is empty, or
-     * the current image is the last image.
+     * It does nothing when no more images are left to be returned which happens when the wand is empty, or the
+     * current image is the last image. Use [hasNextImage] to check for that beforehand.
      *
-     * When the above condition (end of image list) is reached, the iterator is automaticall set so that you can start
-     * using MagickPreviousImage() to again iterate over the images in the reverse direction, starting with the last
-     * image (again). You can jump to this condition immeditally using MagickSetLastIterator().
+     * When the above condition (end of image list) is reached, the iterator is automatically set so that you can start
+     * using [previousImage] to again iterate over the images in the reverse direction, starting with the last image
+     * (again). You can jump to this condition immediately using [setLastIterator].
      */
     @Throws(MagickWandException::class)
     external fun nextImage()
 
+    /**
+     * Sets the previous image in the wand as the current image.
+     *
+     * It is typically used after [setLastIterator], after which its first use will set the last image as the current
+     * image (unless the wand is empty).
+     *
+     * It will return `false` when no more images are left to be returned which happens when the wand is empty, or the
+     * current image is the first image. At that point the iterator is then reset to again process images in the
+     * forward direction, again starting with the first image in list. Images added at this point are prepended.
+     */
+    @Throws(MagickWandException::class)
+    external fun previousImage(): Boolean
+
+    /**
+     * Returns `true` if the wand has more images when traversing the list in the forward direction.
+     */
+    @Throws(MagickWandException::class)
+    external fun hasNextImage(): Boolean
+
+    /**
+     * Returns `true` if the wand has more images when traversing the list in the reverse direction.
+     */
+    @Throws(MagickWandException::class)
+    external fun hasPreviousImage(): Boolean
+
+    /**
+     * Resets the wand iterator.
+     *
+     * It is typically used either before iterating though images, or before calling specific functions such as
+     * [appendAll] to append all images together.
+     *
+     * Afterward you can use [nextImage] to iterate over all the images in a wand container, starting with the first
+     * image.
+     *
+     * Using this before [addImage] or [readImage] will cause new images to be inserted between the first and second
+     * image.
+     */
+    @Throws(MagickWandException::class)
+    external fun resetIterator()
+
+    /**
+     * Sets the wand iterator to the last image.
+     *
+     * The last image is actually the current image, and the next use of [previousImage] will not change this allowing
+     * this function to be used to iterate over the images in the reverse direction. In this sense it is more like
+     * [resetIterator] than [setFirstIterator].
+     *
+     * Typically this function is used before [addImage], [readImage] functions to ensure new images are appended to
+     * the very end of wand's image list.
+     */
+    @Throws(MagickWandException::class)
+    external fun setLastIterator()
+
+    /**
+     * Returns the number of images associated with the wand.
+     */
+    @Throws(MagickWandException::class)
+    external fun getNumberImages(): Long
+
+    /**
+     * Removes the current image from the wand. The next image in the list (if any) becomes the current image.
+     */
+    @Throws(MagickWandException::class)
+    external fun removeImage()
+
+    /**
+     * Gets the current image (frame) from the wand, and returns it as a new [MagickWand] containing just that image.
+     * This wand is left unchanged.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImage(): MagickWand
+
+    /**
+     * Replaces the current image in this wand with the image(s) of [setWand]. The wand iterator is left on the last
+     * replaced image.
+     *
+     * @param setWand The wand holding the replacement image(s).
+     */
+    @Throws(MagickWandException::class)
+    external fun setImage(setWand: MagickWand)
+
+    /**
+     * Inserts the image(s) of [insertWand] so that they start at [index] in this wand. An [index] of `0` prepends the
+     * images, and an index equal to [getNumberImages] appends them to the end.
+     *
+     * &nbsp;
+     *
+     * Afterwards the current image is the last inserted image.
+     *
+     * @param insertWand The wand holding the image(s) to insert.
+     * @param index The position in the image list to insert the image(s) at.
+     */
+    @Throws(MagickWandException::class)
+    external fun insertImageAt(insertWand: MagickWand, index: Long)
+
     /**
      * Sets the wand iterator to the first image.
      *
@@ -327,6 +616,36 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun setOption(key: String, value: String)
 
+    /**
+     * Returns the value associated with the specified option key.
+     *
+     * @param key The option key.
+     * @return The option value, or `null` if the option isn't set.
+     */
+    @Throws(MagickWandException::class)
+    external fun getOption(key: String): String?
+
+    /**
+     * Returns all the option keys that match [pattern].
+     *
+     * @param pattern The pattern to match the keys with, e.g. `"*"` for all of them.
+     */
+    @Throws(MagickWandException::class)
+    external fun getOptions(pattern: String): Array<String>
+
+    /**
+     * Deletes the option associated with [key].
+     *
+     * &nbsp;
+     *
+     * Deleting an option that isn't set is not an error, it just returns `false`.
+     *
+     * @param key The option key.
+     * @return `true` if the option was deleted, `false` if there was none to delete.
+     */
+    @Throws(MagickWandException::class)
+    external fun deleteOption(key: String): Boolean
+
     /**
      * Annotates an image with text.
      *
@@ -339,6 +658,68 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun annotateImage(drawingWand: DrawingWand, x: Double, y: Double, angle: Double, text: String)
 
+    /**
+     * Returns the [FontMetrics] of [text], rendered with the font settings of [drawingWand]. The text is measured as a
+     * single line, use [queryMultilineFontMetrics] for text containing newlines.
+     *
+     * @param drawingWand The [DrawingWand] holding the font settings.
+     * @param text The text to measure.
+     */
+    @Throws(MagickWandException::class)
+    external fun queryFontMetrics(drawingWand: DrawingWand, text: String): FontMetrics
+
+    /**
+     * Returns the [FontMetrics] of [text], rendered with the font settings of [drawingWand]. Newlines in the text
+     * start a new line, so the text width and height are those of the whole block of text.
+     *
+     * @param drawingWand The [DrawingWand] holding the font settings.
+     * @param text The text to measure.
+     */
+    @Throws(MagickWandException::class)
+    external fun queryMultilineFontMetrics(drawingWand: DrawingWand, text: String): FontMetrics
+
+    /**
+     * Annotates the image with [text], using the largest font size between [minSize] and [maxSize] at which the text
+     * fits inside [box]. The text is positioned inside the box according to [gravity], e.g. [GravityType.CenterGravity]
+     * centers every line both horizontally and vertically.
+     *
+     * &nbsp;
+     *
+     * All other font settings (font, color, stroke, spacing, etc) are taken from [drawingWand], which is left unchanged.
+     * If the text doesn't fit even at [minSize], it is drawn at [minSize] anyway and will overflow the box.
+     *
+     * @param drawingWand The [DrawingWand] holding the font settings.
+     * @param text The text to draw. Newlines always start a new line.
+     * @param box The area of the image to fit the text into.
+     * @param gravity Where to position the text inside the box.
+     * @param minSize The smallest font size to use.
+     * @param maxSize The largest font size to use.
+     * @param wrap Word wrap the text to the width of the box.
+     * @return The font size the text was drawn with.
+     */
+    @Throws(MagickWandException::class)
+    fun annotateFitted(
+        drawingWand: DrawingWand,
+        text: String,
+        box: PageGeometry,
+        gravity: GravityType,
+        minSize: Double,
+        maxSize: Double,
+        wrap: Boolean = true
+    ): Double {
+        return magickAnnotateFitted(drawingWand, text, box, gravity.id, minSize, maxSize, wrap)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickAnnotateFitted(
+        drawingWand: DrawingWand,
+        text: String,
+        box: PageGeometry,
+        gravity: Int,
+        minSize: Double,
+        maxSize: Double,
+        wrap: Boolean
+    ): Double
+
     /**
      * Adds a clone of the images from the second wand and inserts them into the first wand.
      *
@@ -372,6 +753,119 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun appendAll(stack: Boolean)
 
+    /**
+     * Creates a composite image by combining several separate images. The images are tiled on the composite image with
+     * the name of the image optionally appearing just below the individual tile. Returns the composite image as a new
+     * wand.
+     *
+     * &nbsp;
+     *
+     * The labels are styled with the font, fill color and pointsize of [drawingWand]. The label of each tile is taken
+     * from the image label (see [labelImage]).
+     *
+     * @param drawingWand The drawing wand. The font name, size, and color are obtained from this wand.
+     * @param tileGeometry The number of tiles per row and page (e.g. `"6x4"`, `"4x"` or `"x3"`).
+     * @param thumbnailGeometry Preferred image size and border size of each thumbnail (e.g. `"120x120+4+3>"`).
+     * @param mode The [MontageMode].
+     * @param frame Surround the image with an ornamental border (e.g. `"15x15+3+3"`). `null` draws no frame.
+     */
+    @Throws(MagickWandException::class)
+    fun montageImage(
+        drawingWand: DrawingWand,
+        tileGeometry: String,
+        thumbnailGeometry: String,
+        mode: MontageMode,
+        frame: String? = null
+    ): MagickWand {
+        return magickMontageImage(drawingWand, tileGeometry, thumbnailGeometry, mode.id, frame)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickMontageImage(
+        drawingWand: DrawingWand,
+        tileGeometry: String,
+        thumbnailGeometry: String,
+        mode: Int,
+        frame: String?
+    ): MagickWand
+
+    /**
+     * Composites a set of images while respecting any page offsets and disposal methods. GIF, MIFF, and MNG animation
+     * sequences typically start with an image background and each subsequent image varies in size and offset. Returns
+     * a new wand with each image in the sequence the same size as the first and composited with the next image in the
+     * sequence.
+     */
+    @Throws(MagickWandException::class)
+    external fun coalesceImages(): MagickWand
+
+    /**
+     * Compares each image the GIF disposed forms of the previous image in the sequence. From this it attempts to
+     * select the smallest cropped image to replace each frame, while preserving the results of the animation. Returns
+     * the optimized sequence as a new wand.
+     */
+    @Throws(MagickWandException::class)
+    external fun optimizeImageLayers(): MagickWand
+
+    /**
+     * Takes a frame optimized GIF animation, and compares the overlayed pixels against the disposal image resulting
+     * from all the previous frames in the animation. Any pixel that does not change the disposal image (and thus does
+     * not change the visual result) is replaced with transparency, which improves compression. Returns the result as
+     * a new wand, this wand is left unchanged.
+     */
+    @Throws(MagickWandException::class)
+    external fun optimizeImageTransparency(): MagickWand
+
+    /**
+     * Composes all the image layers from the current given image onward to produce a single image of the merged
+     * layers, which is returned as a new wand.
+     *
+     * &nbsp;
+     *
+     * The inital canvas's size depends on the given [LayerMethod], and is initialized using the first images
+     * background color. The images are then compositied onto that image in sequence using the given composition that
+     * has been assigned to each individual image.
+     *
+     * @param method The [LayerMethod], one of [LayerMethod.MergeLayer], [LayerMethod.FlattenLayer] or
+     *               [LayerMethod.MosaicLayer].
+     */
+    @Throws(MagickWandException::class)
+    fun mergeImageLayers(method: LayerMethod): MagickWand {
+        return magickMergeImageLayers(method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickMergeImageLayers(method: Int): MagickWand
+
+    /**
+     * Flattens all the layers onto a single canvas the size of the first image's virtual canvas. The same as calling
+     * [mergeImageLayers] with [LayerMethod.FlattenLayer].
+     */
+    @Throws(MagickWandException::class)
+    fun flattenImages(): MagickWand {
+        return mergeImageLayers(LayerMethod.FlattenLayer)
+    }
+
+    /**
+     * Merges all the layers onto a single canvas starting at (0, 0) and large enough to hold all of them. The same as
+     * calling [mergeImageLayers] with [LayerMethod.MosaicLayer].
+     */
+    @Throws(MagickWandException::class)
+    fun mosaicImages(): MagickWand {
+        return mergeImageLayers(LayerMethod.MosaicLayer)
+    }
+
+    /**
+     * Compares each image with the next in a sequence and returns the maximum bounding region of any pixel
+     * differences it discovers, as a new wand.
+     *
+     * @param method The [LayerMethod], one of [LayerMethod.CompareAnyLayer], [LayerMethod.CompareClearLayer] or
+     *               [LayerMethod.CompareOverlayLayer].
+     */
+    @Throws(MagickWandException::class)
+    fun compareImagesLayers(method: LayerMethod): MagickWand {
+        return magickCompareImagesLayers(method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickCompareImagesLayers(method: Int): MagickWand
+
     /**
      * Adds a label to your image.
      *
@@ -437,6 +931,27 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun compareImages(reference: MagickWand, metric: Int): Comparison
 
+    /**
+     * Searches this image for the location that best matches [reference] (template matching), and returns the offset
+     * of the best match along with its similarity and the similarity map.
+     *
+     * &nbsp;
+     *
+     * Throws if ImageMagick couldn't produce the similarity map.
+     *
+     * @param reference Reference wand, the template to look for. It must be smaller than this image.
+     * @param metric The metric.
+     * @param threshold Stop searching as soon as a match with a similarity at or below this value is found. With
+     *                  `0.0` only a perfect match stops the search early.
+     * @return A data class containing the best match offset, its similarity and the similarity map.
+     */
+    @Throws(MagickWandException::class)
+    fun similarityImage(reference: MagickWand, metric: MetricType, threshold: Double = 0.0): Similarity {
+        return similarityImage(reference, metric.id, threshold)
+    }
+    @Throws(MagickWandException::class)
+    private external fun similarityImage(reference: MagickWand, metric: Int, threshold: Double): Similarity
+
     /**
      * Compose another image onto self at ([x],[y]) using composition_operator
      *
@@ -528,9 +1043,100 @@ class MagickWand : Closeable {
     external fun levelImage(blackPoint: Double, gamma: Double, whitePoint: Double)
 
     /**
-     * Extends the image as defined by the geometry, [gravity], and wand background color. Set the ([x],[y]) offset of the
-     * geometry to move the original wand relative to the extended wand.
-     *
+     * Adjusts the levels of a particular image channel by scaling the minimum and maximum values to the full quantum
+     * range.
+     */
+    @Throws(MagickWandException::class)
+    external fun autoLevelImage()
+
+    /**
+     * Extracts the 'mean' from the image and adjust the image to try make set its gamma appropriately.
+     */
+    @Throws(MagickWandException::class)
+    external fun autoGammaImage()
+
+    /**
+     * Enhances the contrast of a color image by adjusting the pixels color to span the entire range of colors
+     * available.
+     *
+     * &nbsp;
+     *
+     * This is the same as the `-normalize` command line option, 2% of the pixels are blacked out and 1% are whited out.
+     */
+    @Throws(MagickWandException::class)
+    external fun normalizeImage()
+
+    /**
+     * Equalizes the image histogram.
+     */
+    @Throws(MagickWandException::class)
+    external fun equalizeImage()
+
+    /**
+     * Enhances the contrast of a color image by adjusting the pixels color to span the entire range of colors
+     * available.
+     *
+     * @param blackPoint The black point, in number of pixels.
+     * @param whitePoint The white point, in number of pixels.
+     */
+    @Throws(MagickWandException::class)
+    external fun contrastStretchImage(blackPoint: Double, whitePoint: Double)
+
+    /**
+     * Stretches with saturation the image intensity.
+     *
+     * @param blackPoint The black point, in number of pixels.
+     * @param whitePoint The white point, in number of pixels.
+     */
+    @Throws(MagickWandException::class)
+    external fun linearStretchImage(blackPoint: Double, whitePoint: Double)
+
+    /**
+     * Adjusts the contrast of an image with a non-linear sigmoidal contrast algorithm. Increase the contrast of the
+     * image using a sigmoidal transfer function without saturating highlights or shadows. Contrast indicates how much
+     * to increase the contrast (0 is none; 3 is typical; 20 is pushing it); mid-point indicates where midtones fall in
+     * the resultant image (0 is white; 50% is middle-gray; 100% is black).
+     *
+     * @param sharpen Increase or decrease image contrast.
+     * @param alpha Strength of the contrast, the larger the number the more 'threshold-like' it becomes.
+     * @param beta Midpoint of the function as a color value 0 to QuantumRange.
+     */
+    @Throws(MagickWandException::class)
+    external fun sigmoidalContrastImage(sharpen: Boolean, alpha: Double, beta: Double)
+
+    /**
+     * Changes the brightness and/or contrast of an image. It converts the brightness and contrast parameters into
+     * slope and intercept and calls a polynomical function to apply to the image.
+     *
+     * @param brightness The brightness percent (-100 .. 100).
+     * @param contrast The contrast percent (-100 .. 100).
+     */
+    @Throws(MagickWandException::class)
+    external fun brightnessContrastImage(brightness: Double, contrast: Double)
+
+    /**
+     * Applies white balancing to an image according to a grayworld assumption in the LAB colorspace.
+     */
+    @Throws(MagickWandException::class)
+    external fun whiteBalanceImage()
+
+    /**
+     * A variant of adaptive histogram equalization in which the contrast amplification is limited, so as to reduce
+     * the problem of noise amplification.
+     *
+     * @param width The width of the tile divisions to use in horizontal direction.
+     * @param height The height of the tile divisions to use in vertical direction.
+     * @param numberBins Number of bins for histogram ("dynamic range").
+     * @param clipLimit Contrast limit for localised changes in contrast. A limit less than 1 results in standard
+     *                  non-contrast limited AHE.
+     */
+    @Throws(MagickWandException::class)
+    external fun claheImage(width: Long, height: Long, numberBins: Double, clipLimit: Double)
+
+    /**
+     * Extends the image as defined by the geometry, [gravity], and wand background color. Set the ([x],[y]) offset of the
+     * geometry to move the original wand relative to the extended wand.
+     *
      * @param width The region width.
      * @param height The region height.
      * @param x The region x offset.
@@ -549,6 +1155,42 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun profileImage(name: String, profile: ByteArray?)
 
+    /**
+     * Returns the named image profile, e.g. `"icc"`, `"exif"`, `"iptc"` or `"xmp"`.
+     *
+     * @param name The name of the profile.
+     * @return The profile, or `null` if the image doesn't have it.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageProfile(name: String): ByteArray?
+
+    /**
+     * Returns the names of all the image profiles that match [pattern].
+     *
+     * @param pattern The pattern to match the names with, e.g. `"*"` for all of them.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageProfiles(pattern: String): Array<String>
+
+    /**
+     * Removes the named image profile and returns it.
+     *
+     * @param name The name of the profile.
+     * @return The removed profile, or `null` if the image didn't have it.
+     */
+    @Throws(MagickWandException::class)
+    external fun removeImageProfile(name: String): ByteArray?
+
+    /**
+     * Adds a named profile to the image. If a profile with the same name already exists, it is replaced. Unlike
+     * [profileImage], the pixels are never converted, the profile is just attached to the image.
+     *
+     * @param name The name of the profile.
+     * @param profile The profile.
+     */
+    @Throws(MagickWandException::class)
+    external fun setImageProfile(name: String, profile: ByteArray)
+
     /**
      * Creates a vertical mirror image by reflecting the pixels around the central x-axis.
      */
@@ -589,6 +1231,74 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun gaussianBlurImage(radius: Double, sigma: Double)
 
+    /**
+     * Simulates motion blur. We convolve the image with a Gaussian operator of the given [radius] and standard
+     * deviation ([sigma]). For reasonable results, [radius] should be larger than [sigma]. Use a [radius] of 0 and
+     * [motionBlurImage] selects a suitable radius for you. [angle] gives the angle of the blurring motion.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param angle Apply the effect along this angle.
+     * @param channel The channel(s) to blur.
+     */
+    @Throws(MagickWandException::class)
+    fun motionBlurImage(radius: Double, sigma: Double, angle: Double, channel: ChannelType = ChannelType.DefaultChannels) {
+        magickMotionBlurImage(radius, sigma, angle, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickMotionBlurImage(radius: Double, sigma: Double, angle: Double, channel: Int)
+
+    /**
+     * Rotational blurs an image.
+     *
+     * @param angle The angle of the blur in degrees.
+     * @param channel The channel(s) to blur.
+     */
+    @Throws(MagickWandException::class)
+    fun rotationalBlurImage(angle: Double, channel: ChannelType = ChannelType.DefaultChannels) {
+        magickRotationalBlurImage(angle, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickRotationalBlurImage(angle: Double, channel: Int)
+
+    /**
+     * Selectively blur an image within a contrast threshold. It is similar to the unsharpen mask that sharpens
+     * everything with contrast above a certain threshold.
+     *
+     * @param radius The radius of the gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the gaussian, in pixels.
+     * @param threshold Only pixels within this contrast threshold are included in the blur operation.
+     * @param channel The channel(s) to blur.
+     */
+    @Throws(MagickWandException::class)
+    fun selectiveBlurImage(
+        radius: Double,
+        sigma: Double,
+        threshold: Double,
+        channel: ChannelType = ChannelType.DefaultChannels
+    ) {
+        magickSelectiveBlurImage(radius, sigma, threshold, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickSelectiveBlurImage(radius: Double, sigma: Double, threshold: Double, channel: Int)
+
+    /**
+     * Adaptively blurs the image by blurring less intensely near image edges and more intensely far from edges. We
+     * blur the image with a Gaussian operator of the given [radius] and standard deviation ([sigma]). For reasonable
+     * results, [radius] should be larger than [sigma]. Use a [radius] of 0 and [adaptiveBlurImage] selects a suitable
+     * radius for you.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param channel The channel(s) to blur.
+     */
+    @Throws(MagickWandException::class)
+    fun adaptiveBlurImage(radius: Double, sigma: Double, channel: ChannelType = ChannelType.DefaultChannels) {
+        magickAdaptiveBlurImage(radius, sigma, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickAdaptiveBlurImage(radius: Double, sigma: Double, channel: Int)
+
     /**
      * Adaptively resize the currently selected image.
      *
@@ -619,6 +1329,27 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun trimImage(fuzz: Double)
 
+    /**
+     * Returns the bounding box of the image content, that is the area [trimImage] would crop the image to. Unlike
+     * [trimImage], the image is left unchanged.
+     *
+     * @param fuzz By default target must match a particular pixel color exactly. However, in many cases two colors may
+     *             differ by a small amount. The fuzz member of image defines how much tolerance is acceptable to
+     *             consider two colors as the same.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageBoundingBox(fuzz: Double): PageGeometry
+
+    /**
+     * Removes skew from the image. Skew is an artifact that occurs in scanned images because of the camera being
+     * misaligned, imperfections in the scanning or surface, or simply because the paper was not placed completely flat
+     * when scanned.
+     *
+     * @param threshold Separate background from foreground, from 0 to QuantumRange (e.g. 40% of it).
+     */
+    @Throws(MagickWandException::class)
+    external fun deskewImage(threshold: Double)
+
     /**
      * Retrieve the width of the image.
      */
@@ -660,6 +1391,68 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun setImageProperty(name: String, value: String)
 
+    /**
+     * Returns all the image property names that match [pattern].
+     *
+     * @param pattern The pattern to match the names with, e.g. `"*"` for all of them or `"exif:*"`.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageProperties(pattern: String): Array<String>
+
+    /**
+     * Deletes the named image property.
+     *
+     * &nbsp;
+     *
+     * Deleting a property that isn't set is not an error, it just returns `false`.
+     *
+     * @param name The name of the property to delete.
+     * @return `true` if the property was deleted, `false` if there was none to delete.
+     */
+    @Throws(MagickWandException::class)
+    external fun deleteImageProperty(name: String): Boolean
+
+    /**
+     * Returns the value of the named image artifact. Artifacts are settings attached to the image that affect how
+     * operations on it behave (e.g. `"distort:viewport"` or `"connected-components:area-threshold"`), and are never
+     * saved with the image.
+     *
+     * @param name The name of the artifact.
+     * @return The artifact value, or `null` if the artifact isn't set.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageArtifact(name: String): String?
+
+    /**
+     * Returns all the image artifact names that match [pattern].
+     *
+     * @param pattern The pattern to match the names with, e.g. `"*"` for all of them.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageArtifacts(pattern: String): Array<String>
+
+    /**
+     * Sets the named image artifact.
+     *
+     * @param name The name of the artifact.
+     * @param value The value of the artifact.
+     */
+    @Throws(MagickWandException::class)
+    external fun setImageArtifact(name: String, value: String)
+
+    /**
+     * Deletes the named image artifact.
+     *
+     * &nbsp;
+     *
+     * Deleting an artifact that isn't set is not an error, it just returns `false`.
+     *
+     * @param name The name of the artifact.
+     * @return `true` if the artifact was deleted, `false` if there was none to delete.
+     */
+    @Throws(MagickWandException::class)
+    external fun deleteImageArtifact(name: String): Boolean
+
     /**
      * Returns a [PixelWand] instance for the pixel specified by [x] and [y] offsets.
      *
@@ -684,6 +1477,77 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun getImageHistogram(): Array<PixelWand>?
 
+    /**
+     * Returns statistics for each channel in the image. The statistics include the channel depth, its minima and
+     * maxima, the mean, the standard deviation, the kurtosis, the skewness and the entropy.
+     *
+     * &nbsp;
+     *
+     * There is one entry for every channel of the image, and a last one for [PixelChannel.CompositePixelChannel]
+     * which holds the statistics of all the channels combined. Meta (extra) channels, such as the extra bands of a
+     * multispectral image, have no [PixelChannel] and are skipped.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageStatistics(): Array<ChannelStatistics>
+
+    /**
+     * Gets the range for one or more image channels.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageRange(): ImageRange
+
+    /**
+     * Gets the mean and standard deviation of one or more image channels.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageMean(): ImageMean
+
+    /**
+     * Gets the kurtosis and skewness of one or more image channels.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageKurtosis(): ImageKurtosis
+
+    /**
+     * Gets the entropy for one or more image channels.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageEntropy(): Double
+
+    /**
+     * Generates an SHA-256 message digest for the image pixel stream. Two images with the exact same pixels have the
+     * same signature, regardless of their format or metadata.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageSignature(): String
+
+    /**
+     * Returns the perceptual hash of each channel in the image. Unlike [getImageSignature], images that look the same
+     * have similar hashes even after resizing, recompression or small color changes.
+     *
+     * &nbsp;
+     *
+     * There is one entry for every channel of the image, and a last one for [PixelChannel.CompositePixelChannel].
+     * Meta (extra) channels have no [PixelChannel] and are skipped.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImagePerceptualHash(): Array<ChannelPerceptualHash>
+
+    /**
+     * Computes the distance between the perceptual hashes of this image and the [other] image (the sum of the squared
+     * differences over all channels). The smaller the distance, the more similar the images look; `0` means the
+     * hashes are identical.
+     *
+     * &nbsp;
+     *
+     * Only the channels both images have are compared, e.g. comparing an sRGB image with a grayscale one only compares
+     * the first channel (red / gray). Throws if the images have no channels in common.
+     *
+     * @param other The wand to compare to.
+     */
+    @Throws(MagickWandException::class)
+    external fun perceptualHashDistance(other: MagickWand): Double
+
     /**
      * Sharpens an image. We convolve the image with a Gaussian operator of the
      * given [radius] and standard deviation ([sigma]). For reasonable results, the
@@ -696,6 +1560,123 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun sharpenImage(radius: Double, sigma: Double)
 
+    /**
+     * Adaptively sharpens the image by sharpening more intensely near image edges and less intensely far from edges.
+     * We sharpen the image with a Gaussian operator of the given [radius] and standard deviation ([sigma]). For
+     * reasonable results, [radius] should be larger than [sigma]. Use a [radius] of 0 and [adaptiveSharpenImage]
+     * selects a suitable radius for you.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param channel The channel(s) to sharpen.
+     */
+    @Throws(MagickWandException::class)
+    fun adaptiveSharpenImage(radius: Double, sigma: Double, channel: ChannelType = ChannelType.DefaultChannels) {
+        magickAdaptiveSharpenImage(radius, sigma, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickAdaptiveSharpenImage(radius: Double, sigma: Double, channel: Int)
+
+    /**
+     * Sharpens an image. We convolve the image with a Gaussian operator of the given [radius] and standard deviation
+     * ([sigma]). For reasonable results, [radius] should be larger than [sigma]. Use a [radius] of 0 and
+     * [unsharpMaskImage] selects a suitable radius for you.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param gain The percentage of the difference between the original and the blur image that is added back into
+     *             the original.
+     * @param threshold The threshold in pixels needed to apply the difference gain.
+     * @param channel The channel(s) to sharpen.
+     */
+    @Throws(MagickWandException::class)
+    fun unsharpMaskImage(
+        radius: Double,
+        sigma: Double,
+        gain: Double,
+        threshold: Double,
+        channel: ChannelType = ChannelType.DefaultChannels
+    ) {
+        magickUnsharpMaskImage(radius, sigma, gain, threshold, channel.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickUnsharpMaskImage(radius: Double, sigma: Double, gain: Double, threshold: Double, channel: Int)
+
+    /**
+     * Applies a user supplied kernel to the image according to the given morphology method.
+     *
+     * @param method The [MorphologyMethod].
+     * @param iterations Apply the operation this many times (or no change). A value of -1 means loop until no change
+     *                   found. How this is applied may depend on the morphology method. Typically this is a value of 1.
+     * @param kernel The [KernelInfo] to use.
+     */
+    @Throws(MagickWandException::class)
+    fun morphologyImage(method: MorphologyMethod, iterations: Long, kernel: KernelInfo) {
+        magickMorphologyImage(method.id, iterations, kernel)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickMorphologyImage(method: Int, iterations: Long, kernel: KernelInfo)
+
+    /**
+     * Applies a custom convolution kernel to the image.
+     *
+     * @param kernel The [KernelInfo] to convolve with.
+     */
+    @Throws(MagickWandException::class)
+    external fun convolveImage(kernel: KernelInfo)
+
+    /**
+     * Apply color transformation to an image. The method permits saturation changes, hue rotation, luminance to alpha,
+     * and various other effects. Although variable-sized transformation matrices can be used, typically one uses a 5x5
+     * matrix for an RGBA image and a 6x6 for CMYKA (or RGBA with offsets). The matrix is similar to those used by Adobe
+     * Flash except offsets are in column 6 rather than 5 (in support of CMYKA images) and offsets are normalized
+     * (divide Flash offset by 255).
+     *
+     * @param colorMatrix The color matrix, as a [KernelInfo].
+     */
+    @Throws(MagickWandException::class)
+    external fun colorMatrixImage(colorMatrix: KernelInfo)
+
+    /**
+     * Uses a multi-stage algorithm to detect a wide range of edges in images.
+     *
+     * @param radius The radius of the gaussian smoothing filter.
+     * @param sigma The sigma of the gaussian smoothing filter.
+     * @param lowerPercent Percentage of edge pixels in the lower threshold.
+     * @param upperPercent Percentage of edge pixels in the upper threshold.
+     */
+    @Throws(MagickWandException::class)
+    external fun cannyEdgeImage(radius: Double, sigma: Double, lowerPercent: Double, upperPercent: Double)
+
+    /**
+     * Can be used in conjunction with any binary edge extracted image (we recommend [cannyEdgeImage]) to identify
+     * lines in the image. The algorithm accumulates counts for every white pixel for every possible orientation (for
+     * angles from 0 to 179 in 1 degree increments) and distance from the center of the image to the corner (in 1 px
+     * increments) and stores the counts in an accumulator matrix of angle vs distance. The image is replaced with the
+     * detected lines drawn on it.
+     *
+     * @param width The width of the neighborhood used to find local maxima in the accumulator.
+     * @param height The height of the neighborhood used to find local maxima in the accumulator.
+     * @param threshold The line count threshold, lines with fewer counts are ignored.
+     */
+    @Throws(MagickWandException::class)
+    external fun houghLineImage(width: Long, height: Long, threshold: Long)
+
+    /**
+     * Connected-component labeling uniquely labels connected objects in an image. The image is replaced with the
+     * labeled image, where each object has its own gray level (its id).
+     *
+     * &nbsp;
+     *
+     * Options such as `connected-components:area-threshold` and `connected-components:mean-color` can be set with
+     * [setImageArtifact] before calling this.
+     *
+     * @param connectivity How many neighbors to visit, choose from 4 or 8.
+     * @return The objects that were found.
+     */
+    @Throws(MagickWandException::class)
+    external fun connectedComponentsImage(connectivity: Long): Array<ConnectedComponent>
+
     /**
      * Set the [background] color.
      */
@@ -767,6 +1748,73 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun magickResizeImage(width: Long, height: Long, filter: Int)
 
+    /**
+     * Parses a resize geometry string against the current image size, returning the size (and offset) the image would
+     * have after resizing with it. The geometry flags are all respected:
+     *
+     * &nbsp;
+     *
+     * `%` - The size is a percentage of the image size.
+     *
+     * `!` - Ignore the aspect ratio, resize to exactly the given size.
+     *
+     * `^` - The size is the minimum size (fill the area), rather than the maximum size.
+     *
+     * `<` - Only enlarge the image if it's smaller than the given size.
+     *
+     * `>` - Only shrink the image if it's larger than the given size.
+     *
+     * `@` - The size is the maximum area in pixels.
+     *
+     * @param geometry The geometry, e.g. `"800x600^"`, `"50%"` or `"1024x1024>"`.
+     */
+    @Throws(MagickWandException::class)
+    external fun parseMetaGeometry(geometry: String): PageGeometry
+
+    /**
+     * Parses a region geometry string (e.g. `"100x50+10+20"` or `"50%x50%"`) against the current image size, returning
+     * the resulting region. The offsets are relative to the image gravity (see [imageGravity]).
+     *
+     * @param geometry The region geometry.
+     */
+    @Throws(MagickWandException::class)
+    external fun parseGravityGeometry(geometry: String): PageGeometry
+
+    /**
+     * Crops and / or resizes the image using geometry strings. The crop is applied first (parsed the same as
+     * [parseGravityGeometry]), then the resize (parsed the same as [parseMetaGeometry]).
+     *
+     * &nbsp;
+     *
+     * For example, `transformImage(null, "800x600^")` fills an 800x600 area while keeping the aspect ratio, and
+     * `transformImage("50%x50%+10+20", null)` crops half of the image starting at (10, 20).
+     *
+     * @param crop The crop geometry, or `null` to not crop.
+     * @param resize The resize geometry, or `null` to not resize.
+     */
+    @Throws(MagickWandException::class)
+    external fun transformImage(crop: String?, resize: String?)
+
+    /**
+     * Extracts a region of the image and returns it as a new wand. Unlike [cropImage], this wand is left unchanged.
+     *
+     * @param width The region width.
+     * @param height The region height.
+     * @param x The region x offset.
+     * @param y The region y offset.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageRegion(width: Long, height: Long, x: Long, y: Long): MagickWand
+
+    /**
+     * Extracts many regions of the image at once, returning each one as a new wand (in the same order as [regions]).
+     * The same as calling [getImageRegion] for every region, but in a single call. This wand is left unchanged.
+     *
+     * @param regions The regions to extract.
+     */
+    @Throws(MagickWandException::class)
+    external fun getImageRegions(regions: Array<PageGeometry>): Array<MagickWand>
+
     /**
      * Extract a region of the image. The [width] and [height] is used as the size
      * of the region. [x] and [y] is the offset.
@@ -814,6 +1862,33 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     external fun liquidRescaleImage(width: Long, height: Long, deltaX: Double, rigidity: Double)
 
+    /**
+     * Distorts an image using various distortion methods, by mapping color lookups of the source image to a new
+     * destination image usually of the same size as the source image, unless [bestFit] is set to true.
+     *
+     * @param method The [DistortMethod] to use.
+     * @param args The arguments for this distortion method.
+     * @param bestFit Attempt to resize destination to fit distorted source.
+     */
+    @Throws(MagickWandException::class)
+    fun distortImage(method: DistortMethod, args: DoubleArray, bestFit: Boolean) {
+        magickDistortImage(method.id, args, bestFit)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickDistortImage(method: Int, args: DoubleArray, bestFit: Boolean)
+
+    /**
+     * Straightens a photographed quadrilateral (e.g. a page of paper) into an [outWidth] x [outHeight] image using a
+     * [DistortMethod.PerspectiveDistortion]. The image is replaced with the straightened result.
+     *
+     * @param corners The 4 corners of the quadrilateral in the image as x,y pairs, in the order top left, top right,
+     *                bottom right, bottom left (8 values in total).
+     * @param outWidth The width of the resulting image.
+     * @param outHeight The height of the resulting image.
+     */
+    @Throws(MagickWandException::class)
+    external fun perspectiveCorrect(corners: DoubleArray, outWidth: Long, outHeight: Long)
+
     /**
      * Implodes the image towards the center by the specified percentage.
      *
@@ -827,6 +1902,238 @@ class MagickWand : Closeable {
     @Throws(MagickWandException::class)
     private external fun implode(amount: Double, method: Int)
 
+    /**
+     * Changes the value of individual pixels based on the intensity of each pixel compared to threshold. The result
+     * is a high-contrast, two color image.
+     *
+     * @param threshold Define the threshold value, from 0 to QuantumRange.
+     */
+    @Throws(MagickWandException::class)
+    external fun thresholdImage(threshold: Double)
+
+    /**
+     * Selects an individual threshold for each pixel based on the range of intensity values in its local
+     * neighborhood. This allows for thresholding of an image whose global intensity histogram doesn't contain
+     * distinctive peaks.
+     *
+     * @param width The width of the local neighborhood.
+     * @param height The height of the local neighborhood.
+     * @param bias The mean bias.
+     */
+    @Throws(MagickWandException::class)
+    external fun adaptiveThresholdImage(width: Long, height: Long, bias: Double)
+
+    /**
+     * Like [thresholdImage] but forces all pixels below the threshold into black while leaving all pixels above the
+     * threshold unchanged.
+     *
+     * @param threshold The threshold color.
+     */
+    @Throws(MagickWandException::class)
+    external fun blackThresholdImage(threshold: PixelWand)
+
+    /**
+     * Like [thresholdImage] but forces all pixels above the threshold into white while leaving all pixels below the
+     * threshold unchanged.
+     *
+     * @param threshold The threshold color.
+     */
+    @Throws(MagickWandException::class)
+    external fun whiteThresholdImage(threshold: PixelWand)
+
+    /**
+     * Changes the value of individual pixels based on the intensity of each pixel compared to a random threshold. The
+     * result is a low-contrast, two color image.
+     *
+     * @param low The low threshold, from 0 to QuantumRange.
+     * @param high The high threshold, from 0 to QuantumRange.
+     */
+    @Throws(MagickWandException::class)
+    external fun randomThresholdImage(low: Double, high: Double)
+
+    /**
+     * Performs an ordered dither based on a number of pre-defined dithering threshold maps, but over multiple
+     * intensity levels, which can be different for different channels, according to the input arguments.
+     *
+     * @param thresholdMap A string containing the name of the threshold dither map to use, followed by zero or more
+     *                     numbers representing the number of color levels to dither between, e.g. `"o8x8"`,
+     *                     `"h4x4a"` or `"o8x8,6"`.
+     */
+    @Throws(MagickWandException::class)
+    external fun orderedDitherImage(thresholdMap: String)
+
+    /**
+     * Automatically performs image thresholding dependent on which method you specify.
+     *
+     * @param method The [AutoThresholdMethod].
+     */
+    @Throws(MagickWandException::class)
+    fun autoThresholdImage(method: AutoThresholdMethod) {
+        magickAutoThresholdImage(method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickAutoThresholdImage(method: Int)
+
+    /**
+     * Adds random noise to the image.
+     *
+     * @param noiseType The type of noise.
+     * @param attenuate Attenuate the random distribution.
+     */
+    @Throws(MagickWandException::class)
+    fun addNoiseImage(noiseType: NoiseType, attenuate: Double = 1.0) {
+        magickAddNoiseImage(noiseType.id, attenuate)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickAddNoiseImage(noiseType: Int, attenuate: Double)
+
+    /**
+     * Reduces the speckle noise in an image while perserving the edges of the original image.
+     */
+    @Throws(MagickWandException::class)
+    external fun despeckleImage()
+
+    /**
+     * Applies a digital filter that improves the quality of a noisy image.
+     */
+    @Throws(MagickWandException::class)
+    external fun enhanceImage()
+
+    /**
+     * Removes noise from the image using a wavelet transform. The wavelet transform is a fast hierarchical scheme for
+     * processing an image using a set of consecutive lowpass and high_pass filters, followed by a decimation. This
+     * results in a decomposition into different scales which can be regarded as different “frequency bands”,
+     * determined by the mother wavelet.
+     *
+     * @param threshold Set the threshold for smoothing.
+     * @param softness Attenuate the smoothing threshold.
+     */
+    @Throws(MagickWandException::class)
+    external fun waveletDenoiseImage(threshold: Double, softness: Double)
+
+    /**
+     * Replace each pixel with corresponding statistic from the neighborhood of the specified width and height.
+     *
+     * @param statisticType The statistic type (median, mode, etc.).
+     * @param width The width of the pixel neighborhood.
+     * @param height The height of the pixel neighborhood.
+     */
+    @Throws(MagickWandException::class)
+    fun statisticImage(statisticType: StatisticType, width: Long, height: Long) {
+        magickStatisticImage(statisticType.id, width, height)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickStatisticImage(statisticType: Int, width: Long, height: Long)
+
+    /**
+     * Softens the edges of the image in vignette style.
+     *
+     * @param radius The radius of the pixel neighborhood.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param x Define the x ellipse offset.
+     * @param y Define the y ellipse offset.
+     */
+    @Throws(MagickWandException::class)
+    external fun vignetteImage(radius: Double, sigma: Double, x: Long, y: Long)
+
+    /**
+     * Swirls the pixels about the center of the image, where [degrees] indicates the sweep of the arc through which
+     * each pixel is moved. You get a more dramatic effect as the degrees move from 1 to 360.
+     *
+     * @param degrees Define the tightness of the swirling effect.
+     * @param method The [PixelInterpolateMethod].
+     */
+    @Throws(MagickWandException::class)
+    fun swirlImage(degrees: Double, method: PixelInterpolateMethod) {
+        magickSwirlImage(degrees, method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickSwirlImage(degrees: Double, method: Int)
+
+    /**
+     * Creates a "ripple" effect in the image by shifting the pixels vertically along a sine wave whose amplitude and
+     * wavelength is specified by the given parameters.
+     *
+     * @param amplitude The amplitude of the sine wave.
+     * @param waveLength The wave length of the sine wave.
+     * @param method The [PixelInterpolateMethod].
+     */
+    @Throws(MagickWandException::class)
+    fun waveImage(amplitude: Double, waveLength: Double, method: PixelInterpolateMethod) {
+        magickWaveImage(amplitude, waveLength, method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickWaveImage(amplitude: Double, waveLength: Double, method: Int)
+
+    /**
+     * Applies a special effect filter that simulates an oil painting. Each pixel is replaced by the most frequent
+     * color occurring in a circular region defined by [radius].
+     *
+     * @param radius The radius of the circular neighborhood.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     */
+    @Throws(MagickWandException::class)
+    external fun oilPaintImage(radius: Double, sigma: Double)
+
+    /**
+     * Simulates a charcoal drawing.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     */
+    @Throws(MagickWandException::class)
+    external fun charcoalImage(radius: Double, sigma: Double)
+
+    /**
+     * Simulates a pencil sketch. We convolve the image with a Gaussian operator of the given radius and standard
+     * deviation (sigma). For reasonable results, radius should be larger than sigma. Use a radius of 0 and it selects
+     * a suitable radius for you.
+     *
+     * @param radius The radius of the Gaussian, in pixels, not counting the center pixel.
+     * @param sigma The standard deviation of the Gaussian, in pixels.
+     * @param angle Apply the effect along this angle.
+     */
+    @Throws(MagickWandException::class)
+    external fun sketchImage(radius: Double, sigma: Double, angle: Double)
+
+    /**
+     * Simulates a Polaroid picture.
+     *
+     * @param drawingWand The [DrawingWand] used to style the caption.
+     * @param caption The Polaroid caption.
+     * @param angle Apply the effect along this angle.
+     * @param method The [PixelInterpolateMethod].
+     */
+    @Throws(MagickWandException::class)
+    fun polaroidImage(drawingWand: DrawingWand, caption: String, angle: Double, method: PixelInterpolateMethod) {
+        magickPolaroidImage(drawingWand, caption, angle, method.id)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickPolaroidImage(drawingWand: DrawingWand, caption: String, angle: Double, method: Int)
+
+    /**
+     * A special effects method that randomly displaces each pixel in a block defined by the [radius] parameter.
+     *
+     * @param method The [PixelInterpolateMethod].
+     * @param radius Choose a random pixel in a neighborhood of this extent.
+     */
+    @Throws(MagickWandException::class)
+    fun spreadImage(method: PixelInterpolateMethod, radius: Double) {
+        magickSpreadImage(method.id, radius)
+    }
+    @Throws(MagickWandException::class)
+    private external fun magickSpreadImage(method: Int, radius: Double)
+
+    /**
+     * Applies a special effect to the image, similar to the effect achieved in a photo darkroom by selectively
+     * exposing areas of photo sensitive paper to light. [threshold] ranges from 0 to QuantumRange and is a measure of
+     * the extent of the solarization.
+     *
+     * @param threshold Define the extent of the solarization.
+     */
+    @Throws(MagickWandException::class)
+    external fun solarizeImage(threshold: Double)
+
     /**
      * Resize the image to fit within the given dimensions, maintaining
      * the current aspect ratio.
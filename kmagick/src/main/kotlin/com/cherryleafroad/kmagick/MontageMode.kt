@@ -0,0 +1,26 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The montage mode used by [MagickWand.montageImage].
+ */
+enum class MontageMode(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedMode(0),
+
+    /**
+     * Draw a decorative frame around each tile, along with a shadow.
+     */
+    FrameMode(1),
+
+    /**
+     * Tiles are drawn without a frame.
+     */
+    UnframeMode(2),
+
+    /**
+     * Tiles are concatenated together without any spacing, borders or frames.
+     */
+    ConcatenateMode(3)
+}
@@ -0,0 +1,121 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The morphology method used by [MagickWand.morphologyImage].
+ */
+enum class MorphologyMethod(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedMorphology(0),
+
+    /**
+     * Weighted sum with the reflected kernel.
+     */
+    ConvolveMorphology(1),
+
+    /**
+     * Weighted sum using a sliding window.
+     */
+    CorrelateMorphology(2),
+
+    /**
+     * Minimum value in the neighbourhood.
+     */
+    ErodeMorphology(3),
+
+    /**
+     * Maximum value in the neighbourhood.
+     */
+    DilateMorphology(4),
+
+    /**
+     * Pixel pick using a grayscale erode.
+     */
+    ErodeIntensityMorphology(5),
+
+    /**
+     * Pixel pick using a grayscale dilate.
+     */
+    DilateIntensityMorphology(6),
+
+    /**
+     * Add the kernel value, take the minimum.
+     */
+    IterativeDistanceMorphology(7),
+
+    /**
+     * Erode then dilate.
+     */
+    OpenMorphology(8),
+
+    /**
+     * Dilate then erode.
+     */
+    CloseMorphology(9),
+
+    /**
+     * Pixel pick using a grayscale open.
+     */
+    OpenIntensityMorphology(10),
+
+    /**
+     * Pixel pick using a grayscale close.
+     */
+    CloseIntensityMorphology(11),
+
+    /**
+     * Open then close.
+     */
+    SmoothMorphology(12),
+
+    /**
+     * Dilate difference from the original.
+     */
+    EdgeInMorphology(13),
+
+    /**
+     * Erode difference from the original.
+     */
+    EdgeOutMorphology(14),
+
+    /**
+     * Dilate difference with erode.
+     */
+    EdgeMorphology(15),
+
+    /**
+     * Open difference from the original.
+     */
+    TopHatMorphology(16),
+
+    /**
+     * Close difference from the original.
+     */
+    BottomHatMorphology(17),
+
+    /**
+     * Foreground / background pattern matching.
+     */
+    HitAndMissMorphology(18),
+
+    /**
+     * Remove matching pixels from the image.
+     */
+    ThinningMorphology(19),
+
+    /**
+     * Add matching pixels to the image.
+     */
+    ThickenMorphology(20),
+
+    /**
+     * Add the kernel value, take the minimum.
+     */
+    DistanceMorphology(21),
+
+    /**
+     * Distance matte channel copy nearest color.
+     */
+    VoronoiMorphology(22)
+}
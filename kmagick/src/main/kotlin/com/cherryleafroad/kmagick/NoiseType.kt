@@ -0,0 +1,46 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The type of noise added by [MagickWand.addNoiseImage].
+ */
+enum class NoiseType(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedNoise(0),
+
+    /**
+     * Uniformly distributed noise.
+     */
+    UniformNoise(1),
+
+    /**
+     * Gaussian (normally distributed) noise.
+     */
+    GaussianNoise(2),
+
+    /**
+     * Gaussian noise whose strength depends on the pixel intensity.
+     */
+    MultiplicativeGaussianNoise(3),
+
+    /**
+     * Impulse ("salt and pepper") noise.
+     */
+    ImpulseNoise(4),
+
+    /**
+     * Laplacian distributed noise.
+     */
+    LaplacianNoise(5),
+
+    /**
+     * Poisson distributed noise.
+     */
+    PoissonNoise(6),
+
+    /**
+     * Random noise.
+     */
+    RandomNoise(7)
+}
@@ -0,0 +1,47 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * A single channel of a pixel. Different colorspaces share the same channel slots, e.g. [RedPixelChannel] is the same
+ * slot as [CyanPixelChannel] and [GrayPixelChannel].
+ *
+ * &nbsp;
+ *
+ * These are channel positions, not colorant names. Channels coming back from ImageMagick are always reported as the
+ * first name of their slot, so the gray channel of a grayscale image or the cyan channel of a CMYK image both come
+ * back as [RedPixelChannel]. Check the image colorspace to tell which colorant a slot holds.
+ */
+enum class PixelChannel(internal val id: Int) {
+    RedPixelChannel(0),
+    CyanPixelChannel(0),
+    GrayPixelChannel(0),
+    LPixelChannel(0),
+    LabelPixelChannel(0),
+    YPixelChannel(0),
+    GreenPixelChannel(1),
+    MagentaPixelChannel(1),
+    aPixelChannel(1),
+    CbPixelChannel(1),
+    BluePixelChannel(2),
+    YellowPixelChannel(2),
+    bPixelChannel(2),
+    CrPixelChannel(2),
+    BlackPixelChannel(3),
+    AlphaPixelChannel(4),
+    IndexPixelChannel(5),
+    ReadMaskPixelChannel(6),
+    WriteMaskPixelChannel(7),
+    MetaPixelChannel(8),
+    CompositeMaskPixelChannel(9),
+
+    /**
+     * All the channels of the image combined.
+     */
+    CompositePixelChannel(64), // MaxPixelChannels in MagickCore/pixel.h
+    IntensityPixelChannel(64); // same as CompositePixelChannel
+
+    internal companion object {
+        fun fromNative(id: Int): PixelChannel {
+            return (PixelChannel::id::find)(id)!!
+        }
+    }
+}
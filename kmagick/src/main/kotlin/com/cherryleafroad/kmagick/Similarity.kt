@@ -0,0 +1,26 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * Holds the result of searching for a reference image using `MagickWand.similarityImage()`
+ */
+data class Similarity(
+    /**
+     * The x offset of the best match.
+     */
+    val x: Long,
+
+    /**
+     * The y offset of the best match.
+     */
+    val y: Long,
+
+    /**
+     * The computed similarity of the best match. Lower is more similar for distance metrics.
+     */
+    val similarity: Double,
+
+    /**
+     * The similarity map, where each pixel is the similarity at that offset.
+     */
+    val similarityImage: MagickWand
+)
@@ -0,0 +1,56 @@
+package com.cherryleafroad.kmagick
+
+/**
+ * The statistic used by [MagickWand.statisticImage] to replace each pixel with, computed from its neighborhood.
+ */
+enum class StatisticType(internal val id: Int) {
+    /**
+     * Unset value.
+     */
+    UndefinedStatistic(0),
+
+    /**
+     * The maximum difference (gradient) in the neighborhood.
+     */
+    GradientStatistic(1),
+
+    /**
+     * The maximum value in the neighborhood.
+     */
+    MaximumStatistic(2),
+
+    /**
+     * The mean (average) of the neighborhood.
+     */
+    MeanStatistic(3),
+
+    /**
+     * The median of the neighborhood.
+     */
+    MedianStatistic(4),
+
+    /**
+     * The minimum value in the neighborhood.
+     */
+    MinimumStatistic(5),
+
+    /**
+     * The most frequent value (mode) in the neighborhood.
+     */
+    ModeStatistic(6),
+
+    /**
+     * The value closest to the neighborhood, excluding the peaks.
+     */
+    NonpeakStatistic(7),
+
+    /**
+     * The root mean square of the neighborhood.
+     */
+    RootMeanSquareStatistic(8),
+
+    /**
+     * The standard deviation of the neighborhood.
+     */
+    StandardDeviationStatistic(9)
+}
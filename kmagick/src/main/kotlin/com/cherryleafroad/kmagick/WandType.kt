@@ -17,5 +17,10 @@ enum class WandType(internal val id: Int) {
     /**
      * MagickWand
      */
-    MagickWand(2)
+    MagickWand(2),
+
+    /**
+     * KernelInfo
+     */
+    KernelInfo(3)
 }
@@ -10,7 +10,7 @@ use lazy_static::lazy_static;
 use jni_tools::Handle;
 
 use crate::{
-    DrawingWand, MagickWand, PixelWand
+    DrawingWand, KernelInfo, MagickWand, PixelWand
 };
 use crate::utils::WandId;
 
@@ -18,13 +18,15 @@ lazy_static! {
     pub static ref PIXELWAND_CACHE: Mutex<FxHashMap<u64, GlobalRef>> = Mutex::new(FxHashMap::default());
     pub static ref DRAWINGWAND_CACHE: Mutex<FxHashMap<u64, GlobalRef>> = Mutex::new(FxHashMap::default());
     pub static ref MAGICKWAND_CACHE: Mutex<FxHashMap<u64, GlobalRef>> = Mutex::new(FxHashMap::default());
+    pub static ref KERNELINFO_CACHE: Mutex<FxHashMap<u64, GlobalRef>> = Mutex::new(FxHashMap::default());
 }
 
 #[derive(N)]
 pub enum CacheType {
     PixelWand,
     DrawingWand,
-    MagickWand
+    MagickWand,
+    KernelInfo
 }
 
 macro_rules! TakeObjs {
@@ -52,16 +54,19 @@ pub fn clear(env: JNIEnv) -> crate::Result<()> {
     let pixel_cache = &mut *PIXELWAND_CACHE.lock()?;
     let magick_cache = &mut *MAGICKWAND_CACHE.lock()?;
     let drawing_cache = &mut *DRAWINGWAND_CACHE.lock()?;
+    let kernel_cache = &mut *KERNELINFO_CACHE.lock()?;
 
     // first we need to take all the objects out
     TakeObjs!(env, PixelWand, pixel_cache);
     TakeObjs!(env, DrawingWand, drawing_cache);
     TakeObjs!(env, MagickWand, magick_cache);
+    TakeObjs!(env, KernelInfo, kernel_cache);
 
     // now clear out all instances
     pixel_cache.clear();
     magick_cache.clear();
     drawing_cache.clear();
+    kernel_cache.clear();
 
     Ok(())
 }
@@ -121,14 +126,17 @@ pub fn clearById(env: JNIEnv, id: &u64) -> crate::Result<()> {
     let pixel_cache = &mut *PIXELWAND_CACHE.lock()?;
     let magick_cache = &mut *MAGICKWAND_CACHE.lock()?;
     let drawing_cache = &mut *DRAWINGWAND_CACHE.lock()?;
+    let kernel_cache = &mut *KERNELINFO_CACHE.lock()?;
 
     TakeObj!(env, PixelWand, pixel_cache, id);
     TakeObj!(env, DrawingWand, drawing_cache, id);
     TakeObj!(env, MagickWand, magick_cache, id);
+    TakeObj!(env, KernelInfo, kernel_cache, id);
 
     let _ =  pixel_cache.remove(id);
     let _ =  drawing_cache.remove(id);
     let _ =  magick_cache.remove(id);
+    let _ =  kernel_cache.remove(id);
 
     Ok(())
 }
@@ -138,14 +146,17 @@ pub fn clearByIds(env: JNIEnv, ids: &[u64]) -> crate::Result<()> {
     let pixel_cache = &mut *PIXELWAND_CACHE.lock()?;
     let magick_cache = &mut *MAGICKWAND_CACHE.lock()?;
     let drawing_cache = &mut *DRAWINGWAND_CACHE.lock()?;
+    let kernel_cache = &mut *KERNELINFO_CACHE.lock()?;
 
     for id in ids {
         TakeObj!(env, PixelWand, pixel_cache, id);
         TakeObj!(env, DrawingWand, drawing_cache, id);
         TakeObj!(env, MagickWand, magick_cache, id);
+        TakeObj!(env, KernelInfo, kernel_cache, id);
         let _ =  pixel_cache.remove(id);
         let _ =  drawing_cache.remove(id);
         let _ =  magick_cache.remove(id);
+        let _ =  kernel_cache.remove(id);
     }
 
     Ok(())
@@ -0,0 +1,160 @@
+use std::convert::TryFrom;
+use std::ffi::CString;
+
+use jni::{JNIEnv, objects::{JObject, JString, JValue}, sys::{jboolean, jdouble, jdoubleArray, jlong}};
+use magick_rust::bindings;
+
+use jni_tools::{Handle, jclass, jdestroy, jignore, jnew, JNIResult, Utils};
+
+use crate::utils::{self, WandId};
+
+/// Wrapper over an ImageMagick KernelInfo used for morphology and convolution.
+/// It isn't a wand, but it's cached and destroyed the exact same way as one.
+pub struct KernelInfo {
+    pub instance: *mut bindings::KernelInfo,
+    pub id: u64
+}
+
+impl WandId for KernelInfo {
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+unsafe impl Send for KernelInfo {}
+
+impl Drop for KernelInfo {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::DestroyKernelInfo(self.instance);
+        }
+    }
+}
+
+#[jclass(pkg="com/cherryleafroad/kmagick", exc="com/cherryleafroad/kmagick/KernelInfoException")]
+impl KernelInfo {
+    #[jnew]
+    fn new(env: JNIEnv, obj: JObject, kernel: JString) -> JNIResult<Self> {
+        let kernel = env.get_jstring(kernel)?;
+        KernelInfo::from_string(env, obj, &*kernel)
+    }
+
+    #[jnew]
+    fn newFromArray(env: JNIEnv, obj: JObject, width: jlong, height: jlong, values: jdoubleArray) -> JNIResult<Self> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        if width == 0 || height == 0 {
+            return utils::runtime_exception(format!("kernel size {width}x{height} is empty"));
+        }
+
+        let len = match width.checked_mul(height) {
+            Some(len) => len,
+            None => return utils::runtime_exception(format!("kernel size {width}x{height} is too large"))
+        };
+
+        let values = utils::get_double_array(env, values)?;
+
+        if values.len() != len {
+            return utils::runtime_exception(
+                format!("expected {len} kernel values for a {width}x{height} kernel, got {}", values.len())
+            );
+        }
+
+        // let imagemagick do the parsing, the same as "WxH: v1,v2,..." kernel strings
+        // NaN values are kept as-is, they're the "don't care" elements of the kernel
+        let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",");
+        KernelInfo::from_string(env, obj, &*format!("{width}x{height}: {values}"))
+    }
+
+    #[jnew]
+    fn clone(env: JNIEnv, obj: JObject, kernel: JObject) -> JNIResult<Self> {
+        let instance = {
+            let kernel = env.get_handle::<KernelInfo>(kernel)?;
+            unsafe { bindings::CloneKernelInfo(kernel.instance) }
+        };
+
+        if instance.is_null() {
+            return utils::runtime_exception("failed to clone kernel");
+        }
+
+        KernelInfo::from_instance(env, obj, instance)
+    }
+
+    #[jignore]
+    fn from_string(env: JNIEnv, obj: JObject, kernel: &str) -> JNIResult<Self> {
+        let c_kernel = CString::new(kernel)?;
+
+        let msg = format!("failed to parse kernel `{kernel}`");
+
+        let (instance, res) = unsafe {
+            let exception = bindings::AcquireExceptionInfo();
+            let instance = bindings::AcquireKernelInfo(c_kernel.as_ptr(), exception);
+            (instance, utils::take_exception(exception, &*msg))
+        };
+
+        // only report imagemagick's reason when there is no kernel to go with it
+        if instance.is_null() {
+            res?;
+            return utils::runtime_exception(msg);
+        }
+
+        KernelInfo::from_instance(env, obj, instance)
+    }
+
+    #[jignore]
+    fn from_instance(env: JNIEnv, obj: JObject, instance: *mut bindings::KernelInfo) -> JNIResult<Self> {
+        let cache = &*crate::cache::KERNELINFO_CACHE;
+        let id = crate::cache::insert(cache, env.new_global_ref(obj)?, "KernelInfo")?;
+
+        let _id = bytemuck::cast::<u64, jlong>(id);
+        env.set_field(obj, "_id", "J", JValue::from(_id))?;
+
+        Ok(Self {
+            instance,
+            id
+        })
+    }
+
+    fn kernelGetWidth(&self) -> JNIResult<jlong> {
+        let width = unsafe { (*self.instance).width };
+        Ok(i64::try_from(width)?)
+    }
+
+    fn kernelGetHeight(&self) -> JNIResult<jlong> {
+        let height = unsafe { (*self.instance).height };
+        Ok(i64::try_from(height)?)
+    }
+
+    fn scaleKernelInfo(&mut self, _: JNIEnv, _: JObject, scale: jdouble, normalize: jboolean) {
+        let flags = match normalize {
+            0 => bindings::GeometryFlags_NoValue,
+            _ => bindings::GeometryFlags_NormalizeValue
+        };
+
+        unsafe {
+            bindings::ScaleKernelInfo(self.instance, scale, flags);
+        }
+    }
+
+    fn scaleGeometryKernelInfo(&mut self, env: JNIEnv, _: JObject, geometry: JString) -> JNIResult<()> {
+        let geometry = env.get_jstring(geometry)?;
+        let c_geometry = CString::new(&*geometry)?;
+
+        // ScaleGeometryKernelInfo silently ignores anything it can't parse
+        let res = unsafe { bindings::IsGeometry(c_geometry.as_ptr()) };
+        utils::magick_result(res, format!("invalid geometry `{geometry}`"))?;
+
+        unsafe {
+            bindings::ScaleGeometryKernelInfo(self.instance, c_geometry.as_ptr());
+        }
+
+        Ok(())
+    }
+
+    // the kernel gets dropped on its own
+    #[jdestroy]
+    fn destroy(&self, env: JNIEnv) {
+        let cache = &*crate::cache::KERNELINFO_CACHE;
+        crate::cache::remove::<KernelInfo>(env, cache, self.id);
+    }
+}
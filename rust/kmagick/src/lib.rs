@@ -14,6 +14,7 @@ use jni_tools::{
     jclass, jignore, jname,
     JNIResult, jstatic, setup_panic, Utils
 };
+pub use kernel_info::KernelInfo;
 pub use magick_wand::MagickWand;
 pub use pixel_wand::PixelWand;
 use utils::Result;
@@ -21,6 +22,7 @@ use utils::Result;
 #[macro_use]
 mod macros;
 mod drawing_wand;
+mod kernel_info;
 mod magick_wand;
 mod pixel_wand;
 mod utils;
@@ -182,6 +184,11 @@ impl Magick {
                 let cache = &*cache::MAGICKWAND_CACHE;
                 cache::destroy_type::<MagickWand>(env, cache)?;
             }
+
+            CacheType::KernelInfo => {
+                let cache = &*cache::KERNELINFO_CACHE;
+                cache::destroy_type::<KernelInfo>(env, cache)?;
+            }
         };
 
         Ok(())
@@ -217,6 +224,11 @@ impl Magick {
                 let cache = &*cache::MAGICKWAND_CACHE;
                 cache::destroy_ids::<MagickWand>(env, cache, slice)?;
             }
+
+            CacheType::KernelInfo => {
+                let cache = &*cache::KERNELINFO_CACHE;
+                cache::destroy_ids::<KernelInfo>(env, cache, slice)?;
+            }
         };
 
         Ok(())
@@ -243,6 +255,11 @@ impl Magick {
                 let cache = &*cache::MAGICKWAND_CACHE;
                 cache::remove::<MagickWand>(env, cache, id);
             }
+
+            CacheType::KernelInfo => {
+                let cache = &*cache::KERNELINFO_CACHE;
+                cache::remove::<KernelInfo>(env, cache, id);
+            }
         };
     }
 
@@ -580,9 +580,7 @@ macro_rules! magick_enum_int_conversion {
     ($vis:vis enum $name:ident {
         $($vname:ident,)*
     }) => {
-        use crate::utils::EnumIntConversion;
-
-        impl EnumIntConversion for magick_rust::$name {
+        impl crate::utils::EnumIntConversion for magick_rust::$name {
             type Output = magick_rust::$name;
 
             fn try_from_int(v: i32) -> crate::utils::Result<magick_rust::$name> {
@@ -592,7 +590,28 @@ macro_rules! magick_enum_int_conversion {
                 }
             }
         }
-    }
+    };
+
+    // for enums magick_rust doesn't wrap, we only have the raw bindings constants
+    // (e.g. bindings::DistortMethod_AffineDistortion), so make a marker type to hang the conversion off of
+    (bindings enum $name:ident {
+        $($vname:ident,)*
+    }) => {
+        pub struct $name;
+
+        paste::paste! {
+            impl crate::utils::EnumIntConversion for $name {
+                type Output = magick_rust::bindings::$name;
+
+                fn try_from_int(v: i32) -> crate::utils::Result<magick_rust::bindings::$name> {
+                    match v {
+                        $(x if x == magick_rust::bindings::[<$name _ $vname>] as i32 => Ok(magick_rust::bindings::[<$name _ $vname>]),)*
+                        _ => crate::utils::runtime_exception(concat!(stringify!($name), " failed enum to int conversion")),
+                    }
+                }
+            }
+        }
+    };
 }
 
 macro_rules! new_from_wand {
@@ -1,16 +1,20 @@
 #![allow(non_snake_case)]
 
 use std::convert::TryFrom;
+use std::ffi::CString;
 
 use jni::{JNIEnv, objects::{JObject, JString, JValue}, sys::{jboolean, jbyteArray, jdouble, jdoubleArray, jint, jlong, jobject, jobjectArray, jstring}};
 use jni::sys::jsize;
 
-use jni_tools::{Handle, jclass, jname, JNIResult, Utils};
+use jni_tools::{Handle, jclass, jignore, jname, JNIResult, Utils};
+use magick_rust::bindings;
 
 use crate::{
     DrawingWand,
+    KernelInfo,
     PixelWand
 };
+use crate::utils::{self, EnumIntConversion};
 
 wand_wrapper!(MagickWand);
 
@@ -31,6 +35,125 @@ magick_enum_int_conversion!(
     }
 );
 
+magick_enum_int_conversion!(
+    bindings enum DistortMethod {
+        UndefinedDistortion,
+        AffineDistortion,
+        AffineProjectionDistortion,
+        ScaleRotateTranslateDistortion,
+        PerspectiveDistortion,
+        PerspectiveProjectionDistortion,
+        BilinearForwardDistortion,
+        BilinearReverseDistortion,
+        PolynomialDistortion,
+        ArcDistortion,
+        PolarDistortion,
+        DePolarDistortion,
+        Cylinder2PlaneDistortion,
+        Plane2CylinderDistortion,
+        BarrelDistortion,
+        BarrelInverseDistortion,
+        ShepardsDistortion,
+        ResizeDistortion,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum MorphologyMethod {
+        UndefinedMorphology,
+        ConvolveMorphology,
+        CorrelateMorphology,
+        ErodeMorphology,
+        DilateMorphology,
+        ErodeIntensityMorphology,
+        DilateIntensityMorphology,
+        IterativeDistanceMorphology,
+        OpenMorphology,
+        CloseMorphology,
+        OpenIntensityMorphology,
+        CloseIntensityMorphology,
+        SmoothMorphology,
+        EdgeInMorphology,
+        EdgeOutMorphology,
+        EdgeMorphology,
+        TopHatMorphology,
+        BottomHatMorphology,
+        HitAndMissMorphology,
+        ThinningMorphology,
+        ThickenMorphology,
+        DistanceMorphology,
+        VoronoiMorphology,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum LayerMethod {
+        UndefinedLayer,
+        CoalesceLayer,
+        CompareAnyLayer,
+        CompareClearLayer,
+        CompareOverlayLayer,
+        DisposeLayer,
+        OptimizeLayer,
+        OptimizeImageLayer,
+        OptimizePlusLayer,
+        OptimizeTransLayer,
+        RemoveDupsLayer,
+        RemoveZeroLayer,
+        CompositeLayer,
+        MergeLayer,
+        FlattenLayer,
+        MosaicLayer,
+        TrimBoundsLayer,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum MontageMode {
+        UndefinedMode,
+        FrameMode,
+        UnframeMode,
+        ConcatenateMode,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum NoiseType {
+        UndefinedNoise,
+        UniformNoise,
+        GaussianNoise,
+        MultiplicativeGaussianNoise,
+        ImpulseNoise,
+        LaplacianNoise,
+        PoissonNoise,
+        RandomNoise,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum StatisticType {
+        UndefinedStatistic,
+        GradientStatistic,
+        MaximumStatistic,
+        MeanStatistic,
+        MedianStatistic,
+        MinimumStatistic,
+        ModeStatistic,
+        NonpeakStatistic,
+        RootMeanSquareStatistic,
+        StandardDeviationStatistic,
+    }
+);
+
+magick_enum_int_conversion!(
+    bindings enum AutoThresholdMethod {
+        UndefinedThresholdMethod,
+        KapurThresholdMethod,
+        OTSUThresholdMethod,
+        TriangleThresholdMethod,
+    }
+);
+
 #[jclass(pkg="com/cherryleafroad/kmagick", exc="com/cherryleafroad.kmagick/MagickWandException")]
 impl MagickWand {
     fn newImage(&self, env: JNIEnv, _: JObject, columns: jlong, rows: jlong, pixel_wand: JObject) -> JNIResult<()> {
@@ -69,6 +192,45 @@ impl MagickWand {
         Ok(jenum)
     }
 
+    fn magickSeparateImage(&self, env: JNIEnv, _: JObject, channel: jint) -> JNIResult<jobject> {
+        #[cfg(target_os="android")]
+        let channel = u32::try_from(channel)?;
+
+        // separating works in place, so do it on a wand holding just the current image and leave self alone
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickGetImage(self.wand) },
+            "failed to get image"
+        )?;
+
+        let res = unsafe { bindings::MagickSeparateImage(wand.wand, channel) };
+        utils::magick_result(res, "failed to separate image")?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn magickCombineImages(&self, env: JNIEnv, _: JObject, colorspace: jint) -> JNIResult<jobject> {
+        #[cfg(target_os="android")]
+        let colorspace = u32::try_from(colorspace)?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickCombineImages(self.wand, colorspace) },
+            "failed to combine images"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn channelFxImage(&self, env: JNIEnv, _: JObject, expression: JString) -> JNIResult<jobject> {
+        let expression = CString::new(env.get_jstring(expression)?)?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickChannelFxImage(self.wand, expression.as_ptr()) },
+            "failed to channel fx image"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
     fn magickEvaluateImage(&mut self, _: JNIEnv, _: JObject, op: jint, val: jdouble) -> JNIResult<()> {
         #[cfg(target_os="android")]
         let op = op as u32;
@@ -76,6 +238,91 @@ impl MagickWand {
         res_to_jniresult!(self.evaluate_image(op, val))
     }
 
+    fn magickEvaluateImages(&self, env: JNIEnv, _: JObject, op: jint) -> JNIResult<jobject> {
+        #[cfg(target_os="android")]
+        let op = u32::try_from(op)?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickEvaluateImages(self.wand, op) },
+            "failed to evaluate images"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn forwardFourierTransformImage(&self, env: JNIEnv, _: JObject, magnitude: jboolean) -> JNIResult<jobject> {
+        // the transform replaces the image with its 2 frames, so do it on a wand holding just the current image
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickGetImage(self.wand) },
+            "failed to get image"
+        )?;
+
+        let res = unsafe {
+            bindings::MagickForwardFourierTransformImage(wand.wand, utils::to_magick_bool(magnitude))
+        };
+        utils::magick_result(res, "failed to forward fourier transform image")?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn inverseFourierTransformImage(&self, env: JNIEnv, _: JObject, magnitude: jboolean) -> JNIResult<jobject> {
+        let number = unsafe { bindings::MagickGetNumberImages(self.wand) };
+        if number < 2 {
+            return utils::runtime_exception("inverse fourier transform needs a wand with 2 images");
+        }
+
+        // the first frame is the magnitude (or real) one, the second is phase (or imaginary).
+        // pick both out of self directly rather than cloning every frame, then put the iterator back
+        let previous = unsafe { bindings::MagickGetIteratorIndex(self.wand) };
+
+        let get_frame = |index: isize, msg: &str| -> utils::Result<magick_rust::MagickWand> {
+            let res = unsafe { bindings::MagickSetIteratorIndex(self.wand, index) };
+            utils::magick_result(res, msg)?;
+
+            utils::new_wand(unsafe { bindings::MagickGetImage(self.wand) }, msg)
+        };
+
+        let magnitude_wand = get_frame(0, "failed to get magnitude image");
+        let phase_wand = get_frame(1, "failed to get phase image");
+
+        unsafe { bindings::MagickSetIteratorIndex(self.wand, previous) };
+
+        let magnitude_wand = magnitude_wand?;
+        let phase_wand = phase_wand?;
+
+        let res = unsafe {
+            bindings::MagickInverseFourierTransformImage(
+                magnitude_wand.wand,
+                phase_wand.wand,
+                utils::to_magick_bool(magnitude)
+            )
+        };
+        utils::magick_result(res, "failed to inverse fourier transform image")?;
+
+        Ok(new_from_wand!(env, magnitude_wand, MagickWand).into_inner())
+    }
+
+    fn polynomialImage(&self, env: JNIEnv, _: JObject, terms: jdoubleArray) -> JNIResult<jobject> {
+        let terms = utils::get_double_array(env, terms)?;
+        if terms.len() % 2 != 0 {
+            return utils::runtime_exception("polynomial terms must be weight and exponent pairs");
+        }
+
+        // polynomial replaces the current image in place, so run it on a copy (which starts at
+        // the first frame) and hand back just the result
+        let copy = self.instance.clone();
+
+        let res = unsafe { bindings::MagickPolynomialImage(copy.wand, terms.len() / 2, terms.as_ptr()) };
+        utils::magick_result(res, "failed to polynomial image")?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickGetImage(copy.wand) },
+            "failed to get polynomial image"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
     fn magickBorderImage(
         &self,
         env: JNIEnv,
@@ -95,6 +342,100 @@ impl MagickWand {
         res_to_jniresult!(self.border_image(&pixel_wand, width as usize, height as usize, compose))
     }
 
+    fn magickFrameImage(
+        &self,
+        env: JNIEnv,
+        _: JObject,
+        matte_color: JObject,
+        width: jlong,
+        height: jlong,
+        inner_bevel: jlong,
+        outer_bevel: jlong,
+        compose: jint
+    ) -> JNIResult<()> {
+        let matte_color = env.get_handle::<PixelWand>(matte_color)?;
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let inner_bevel = isize::try_from(inner_bevel)?;
+        let outer_bevel = isize::try_from(outer_bevel)?;
+
+        #[cfg(target_os="android")]
+        let compose = u32::try_from(compose)?;
+
+        let res = unsafe {
+            bindings::MagickFrameImage(self.wand, matte_color.wand, width, height, inner_bevel, outer_bevel, compose)
+        };
+        Ok(utils::magick_result(res, "failed to frame image")?)
+    }
+
+    fn raiseImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, x: jlong, y: jlong, raise: jboolean) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        let res = unsafe {
+            bindings::MagickRaiseImage(self.wand, width, height, x, y, utils::to_magick_bool(raise))
+        };
+        Ok(utils::magick_result(res, "failed to raise image")?)
+    }
+
+    fn chopImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, x: jlong, y: jlong) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        let res = unsafe { bindings::MagickChopImage(self.wand, width, height, x, y) };
+        Ok(utils::magick_result(res, "failed to chop image")?)
+    }
+
+    fn spliceImage(
+        &self,
+        env: JNIEnv,
+        _: JObject,
+        pixel_wand: JObject,
+        width: jlong,
+        height: jlong,
+        x: jlong,
+        y: jlong
+    ) -> JNIResult<()> {
+        let pixel_wand = env.get_handle::<PixelWand>(pixel_wand)?;
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        // splice fills with the image background color, so swap it for the given color while splicing
+        let previous = magick_rust::PixelWand::new();
+        let res = unsafe {
+            bindings::MagickGetImageBackgroundColor(self.wand, previous.wand);
+            bindings::MagickSetImageBackgroundColor(self.wand, pixel_wand.wand);
+
+            let res = bindings::MagickSpliceImage(self.wand, width, height, x, y);
+
+            bindings::MagickSetImageBackgroundColor(self.wand, previous.wand);
+            res
+        };
+        Ok(utils::magick_result(res, "failed to splice image")?)
+    }
+
+    fn shaveImage(&self, _: JNIEnv, _: JObject, columns: jlong, rows: jlong) -> JNIResult<()> {
+        let columns = usize::try_from(columns)?;
+        let rows = usize::try_from(rows)?;
+
+        let res = unsafe { bindings::MagickShaveImage(self.wand, columns, rows) };
+        Ok(utils::magick_result(res, "failed to shave image")?)
+    }
+
+    fn rollImage(&self, _: JNIEnv, _: JObject, x: jlong, y: jlong) -> JNIResult<()> {
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        let res = unsafe { bindings::MagickRollImage(self.wand, x, y) };
+        Ok(utils::magick_result(res, "failed to roll image")?)
+    }
+
     fn shadowImage(
         &self,
         _: JNIEnv,
@@ -130,6 +471,80 @@ impl MagickWand {
         self.next_image() as jboolean
     }
 
+    fn previousImage(&self) -> jboolean {
+        let res = unsafe { bindings::MagickPreviousImage(self.wand) };
+        (res == bindings::MagickBooleanType_MagickTrue) as jboolean
+    }
+
+    fn hasNextImage(&self) -> jboolean {
+        let res = unsafe { bindings::MagickHasNextImage(self.wand) };
+        (res == bindings::MagickBooleanType_MagickTrue) as jboolean
+    }
+
+    fn hasPreviousImage(&self) -> jboolean {
+        let res = unsafe { bindings::MagickHasPreviousImage(self.wand) };
+        (res == bindings::MagickBooleanType_MagickTrue) as jboolean
+    }
+
+    fn resetIterator(&self) {
+        unsafe { bindings::MagickResetIterator(self.wand) }
+    }
+
+    fn setLastIterator(&self) {
+        unsafe { bindings::MagickSetLastIterator(self.wand) }
+    }
+
+    fn getNumberImages(&self) -> JNIResult<jlong> {
+        let number = unsafe { bindings::MagickGetNumberImages(self.wand) };
+        Ok(i64::try_from(number)?)
+    }
+
+    fn removeImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickRemoveImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to remove image")?)
+    }
+
+    fn getImage(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickGetImage(self.wand) },
+            "failed to get image"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn setImage(&self, env: JNIEnv, _: JObject, set_wand: JObject) -> JNIResult<()> {
+        let r_obj = env.get_handle::<MagickWand>(set_wand)?;
+
+        let res = unsafe { bindings::MagickSetImage(self.wand, r_obj.wand) };
+        Ok(utils::magick_result(res, "failed to set image")?)
+    }
+
+    fn insertImageAt(&self, env: JNIEnv, _: JObject, insert_wand: JObject, index: jlong) -> JNIResult<()> {
+        let r_obj = env.get_handle::<MagickWand>(insert_wand)?;
+
+        let number = unsafe { bindings::MagickGetNumberImages(self.wand) } as usize;
+        let index = usize::try_from(index)?;
+        if index > number {
+            return utils::runtime_exception(format!("index {index} is out of bounds for {number} images"));
+        }
+
+        // MagickAddImage inserts after the current image, unless the iterator was set to the first image,
+        // in which case it prepends instead
+        let res = unsafe {
+            if index == 0 {
+                bindings::MagickSetFirstIterator(self.wand);
+                bindings::MagickBooleanType_MagickTrue
+            } else {
+                bindings::MagickSetIteratorIndex(self.wand, (index - 1) as isize)
+            }
+        };
+        utils::magick_result(res, "failed to set iterator index")?;
+
+        let res = unsafe { bindings::MagickAddImage(self.wand, r_obj.wand) };
+        Ok(utils::magick_result(res, "failed to insert image")?)
+    }
+
     fn thumbnailImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong) {
         let width = bytemuck::cast::<jlong, u64>(width);
         let height = bytemuck::cast::<jlong, u64>(height);
@@ -151,6 +566,30 @@ impl MagickWand {
         Ok(self.set_option(&*key, &*value)?)
     }
 
+    fn getOption(&self, env: JNIEnv, _: JObject, key: JString) -> JNIResult<jstring> {
+        let key = CString::new(env.get_jstring(key)?)?;
+
+        let value = unsafe { bindings::MagickGetOption(self.wand, key.as_ptr()) };
+        MagickWand::take_string(env, value)
+    }
+
+    fn getOptions(&self, env: JNIEnv, _: JObject, pattern: JString) -> JNIResult<jobjectArray> {
+        let pattern = CString::new(env.get_jstring(pattern)?)?;
+
+        let mut number = 0usize;
+        let options = unsafe { bindings::MagickGetOptions(self.wand, pattern.as_ptr(), &mut number) };
+
+        MagickWand::take_string_list(env, options, number)
+    }
+
+    fn deleteOption(&self, env: JNIEnv, _: JObject, key: JString) -> JNIResult<jboolean> {
+        let key = CString::new(env.get_jstring(key)?)?;
+
+        // false only means there was nothing to delete
+        let res = unsafe { bindings::MagickDeleteOption(self.wand, key.as_ptr()) };
+        Ok((res == bindings::MagickBooleanType_MagickTrue) as jboolean)
+    }
+
     fn annotateImage(&mut self, env: JNIEnv, _: JObject, drawing_wand: JObject, x: jdouble, y: jdouble, angle: jdouble, text: JString) -> JNIResult<()> {
         let r_obj = env.get_handle::<DrawingWand>(drawing_wand)?;
         let text = env.get_jstring(text)?;
@@ -158,76 +597,394 @@ impl MagickWand {
         Ok(self.annotate_image(&r_obj.instance, x, y, angle, &*text)?)
     }
 
-    fn addImage(&mut self, env: JNIEnv, _: JObject, other_wand: JObject) -> JNIResult<()> {
-        let r_obj = env.get_handle::<MagickWand>(other_wand)?;
-        Ok(self.add_image(&r_obj.instance)?)
+    fn queryFontMetrics(&self, env: JNIEnv, _: JObject, drawing_wand: JObject, text: JString) -> JNIResult<jobject> {
+        let drawing_wand = env.get_handle::<DrawingWand>(drawing_wand)?;
+        let text = env.get_jstring(text)?;
+
+        let metrics = self.query_font_metrics(drawing_wand.wand, &*text, false)?;
+        MagickWand::new_font_metrics(env, &metrics)
     }
 
-    fn appendAll(&mut self, env: JNIEnv, _: JObject, stack: jboolean) -> JNIResult<jobject> {
-        let wand = self.append_all(stack != 0);
+    fn queryMultilineFontMetrics(&self, env: JNIEnv, _: JObject, drawing_wand: JObject, text: JString) -> JNIResult<jobject> {
+        let drawing_wand = env.get_handle::<DrawingWand>(drawing_wand)?;
+        let text = env.get_jstring(text)?;
 
-        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+        let metrics = self.query_font_metrics(drawing_wand.wand, &*text, true)?;
+        MagickWand::new_font_metrics(env, &metrics)
     }
 
-    fn writeImages(&self, env: JNIEnv, _: JObject, path: JString, adjoin: jboolean) -> JNIResult<()> {
-        let path = env.get_jstring(path)?;
-        Ok(self.write_images(&*path, adjoin != 0)?)
-    }
+    // imagemagick hands back 13 values, in the same order as the FontMetrics constructor
+    #[jignore]
+    fn query_font_metrics(&self, drawing_wand: *mut bindings::DrawingWand, text: &str, multiline: bool) -> utils::Result<Vec<f64>> {
+        let c_text = CString::new(text)?;
 
-    fn readImageBlob(&self, env: JNIEnv, _: JObject, data: jbyteArray) -> JNIResult<()> {
-        let bytes = env.convert_byte_array(data)?;
-        Ok(self.read_image_blob(bytes)?)
+        let metrics = unsafe {
+            if multiline {
+                bindings::MagickQueryMultilineFontMetrics(self.wand, drawing_wand, c_text.as_ptr())
+            } else {
+                bindings::MagickQueryFontMetrics(self.wand, drawing_wand, c_text.as_ptr())
+            }
+        };
+
+        if metrics.is_null() {
+            return utils::runtime_exception("failed to query font metrics");
+        }
+
+        let res = unsafe { std::slice::from_raw_parts(metrics, 13) }.to_vec();
+
+        unsafe {
+            bindings::MagickRelinquishMemory(metrics as *mut std::os::raw::c_void);
+        }
+
+        Ok(res)
     }
 
-    fn pingImageBlob(&self, env: JNIEnv, _: JObject, data: jbyteArray) -> JNIResult<()> {
-        let bytes = env.convert_byte_array(data)?;
-        Ok(self.ping_image_blob(bytes)?)
+    #[jignore]
+    fn new_font_metrics(env: JNIEnv, metrics: &[f64]) -> JNIResult<jobject> {
+        let args = metrics.iter().map(|m| JValue::Double(*m)).collect::<Vec<_>>();
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/FontMetrics")?;
+        let mid = env.get_method_id(cls, "<init>", "(DDDDDDDDDDDDD)V")?;
+        Ok(env.new_object_unchecked(cls, mid, &args)?.into_inner())
     }
 
-    fn compareImages(&self, env: JNIEnv, _: JObject, reference: JObject, metric: jint) -> JNIResult<jobject> {
-        let reference = env.get_handle::<MagickWand>(reference)?;
+    fn magickAnnotateFitted(
+        &mut self,
+        env: JNIEnv,
+        _: JObject,
+        drawing_wand: JObject,
+        text: JString,
+        text_box: JObject,
+        gravity: jint,
+        min_size: jdouble,
+        max_size: jdouble,
+        wrap: jboolean
+    ) -> JNIResult<jdouble> {
+        let text = env.get_jstring(text)?;
+        let box_width = env.get_field(text_box, "width", "J")?.j()? as f64;
+        let box_height = env.get_field(text_box, "height", "J")?.j()? as f64;
+        let box_x = env.get_field(text_box, "x", "J")?.j()? as f64;
+        let box_y = env.get_field(text_box, "y", "J")?.j()? as f64;
 
         #[cfg(target_os="android")]
-        let metric = u32::try_from(metric)?;
+        let gravity = u32::try_from(gravity)?;
 
-        let (distortion, r_diffImage) = self.compare_images(&reference.instance, metric);
+        if min_size <= 0.0 || min_size > max_size {
+            return utils::runtime_exception(format!("invalid font size range {min_size}..{max_size}"));
+        }
 
-        let mut diffImage = None;
-        if r_diffImage.is_some() {
-            let wand = r_diffImage.unwrap();
-            diffImage = Some(new_from_wand!(env, wand, MagickWand));
+        // nothing to draw (and nothing imagemagick can measure)
+        if text.trim().is_empty() {
+            return Ok(max_size);
         }
 
-        let cls = env.find_class("com/cherryleafroad/kmagick/Comparison")?;
-        let j_distortion = JValue::Double(distortion);
-        let j_diffImage = JValue::Object(
-            diffImage.unwrap_or(JObject::null())
-        );
-        let mid = env.get_method_id(cls, "<init>", "(DLcom/cherryleafroad/kmagick/MagickWand;)V")?;
+        // work on a copy so the caller's font size, gravity and alignment are left alone
+        let mut dw = env.get_handle::<DrawingWand>(drawing_wand)?.instance.clone();
+        dw.set_gravity(bindings::GravityType_UndefinedGravity);
+        dw.set_text_alignment(bindings::AlignType_LeftAlign);
+
+        let wrap = wrap != 0;
+        let fits = |&(_, width, height): &(Vec<String>, f64, f64)| width <= box_width && height <= box_height;
+
+        // find the largest size that fits. if not even min_size fits, the text is drawn at min_size anyway
+        let mut size = max_size;
+        let mut layout = self.layout_text(&mut dw, &text, size, box_width, wrap)?;
+        if !fits(&layout) {
+            size = min_size;
+            layout = self.layout_text(&mut dw, &text, size, box_width, wrap)?;
+
+            let mut hi = max_size;
+            while fits(&layout) && hi - size > 0.5 {
+                let mid = (size + hi) / 2.0;
+                let mid_layout = self.layout_text(&mut dw, &text, mid, box_width, wrap)?;
+
+                if fits(&mid_layout) {
+                    size = mid;
+                    layout = mid_layout;
+                } else {
+                    hi = mid;
+                }
+            }
+        }
+        dw.set_font_size(size);
 
-        let comparison = env.new_object_unchecked(cls, mid, &[j_distortion, j_diffImage])?;
+        let (lines, _, block_height) = layout;
 
-        Ok(comparison.into_inner())
-    }
+        let h_align = match gravity {
+            bindings::GravityType_NorthGravity | bindings::GravityType_CenterGravity | bindings::GravityType_SouthGravity => 0.5,
+            bindings::GravityType_NorthEastGravity | bindings::GravityType_EastGravity | bindings::GravityType_SouthEastGravity => 1.0,
+            _ => 0.0
+        };
+        let v_align = match gravity {
+            bindings::GravityType_WestGravity | bindings::GravityType_CenterGravity | bindings::GravityType_EastGravity => 0.5,
+            bindings::GravityType_SouthWestGravity | bindings::GravityType_SouthGravity | bindings::GravityType_SouthEastGravity => 1.0,
+            _ => 0.0
+        };
 
-    fn compositeImage(&self, env: JNIEnv, _: JObject, reference: JObject, composition_operator: jint, clip_to_self: jboolean, x: jlong, y: jlong) -> JNIResult<()> {
-        let reference = env.get_handle::<MagickWand>(reference)?;
+        // imagemagick's multiline height is lines * (ascent - descent + interline spacing) - interline spacing
+        let first_line = lines.iter().find(|l| !l.is_empty()).map_or("", |l| l.as_str());
+        let ascender = self.query_font_metrics(dw.wand, first_line, false)?[2];
+        let line_height = (block_height + dw.get_text_interline_spacing()) / lines.len() as f64;
+        let top = box_y + (box_height - block_height) * v_align;
 
-        #[cfg(target_os="android")]
-        let composition_operator = u32::try_from(composition_operator)?;
+        for (i, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
 
-        self.compose_images(&reference.instance, composition_operator, clip_to_self != 0, x as isize, y as isize)?;
-        Ok(())
-    }
+            let line_width = self.query_font_metrics(dw.wand, line, false)?[4];
+            let x = box_x + (box_width - line_width) * h_align;
+            let y = top + i as f64 * line_height + ascender;
 
-    fn clutImage(&self, env: JNIEnv, _: JObject, clut_wand: JObject, method: jint) -> JNIResult<()> {
-        let clut_wand = env.get_handle::<MagickWand>(clut_wand)?;
+            self.annotate_image(&dw, x, y, 0.0, line)?;
+        }
 
-        #[cfg(target_os="android")]
-        let method = u32::try_from(method)?;
+        Ok(size)
+    }
 
-        self.clut_image(&clut_wand.instance, method)?;
-        Ok(())
+    // sets the font size and lays the text out, returning the lines with the total width and height of the block
+    #[jignore]
+    fn layout_text(
+        &self,
+        drawing_wand: &mut magick_rust::DrawingWand,
+        text: &str,
+        size: f64,
+        width: f64,
+        wrap: bool
+    ) -> utils::Result<(Vec<String>, f64, f64)> {
+        drawing_wand.set_font_size(size);
+
+        let lines = if wrap {
+            self.wrap_text(drawing_wand.wand, text, width)?
+        } else {
+            text.split('\n').map(String::from).collect()
+        };
+
+        let metrics = self.query_font_metrics(drawing_wand.wand, &lines.join("\n"), true)?;
+        Ok((lines, metrics[4], metrics[5]))
+    }
+
+    // greedy word wrap, existing newlines always start a new line
+    #[jignore]
+    fn wrap_text(&self, drawing_wand: *mut bindings::DrawingWand, text: &str, width: f64) -> utils::Result<Vec<String>> {
+        let mut lines = vec![];
+
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+
+            for word in paragraph.split_whitespace() {
+                if line.is_empty() {
+                    line.push_str(word);
+                    continue;
+                }
+
+                let candidate = format!("{line} {word}");
+                if self.query_font_metrics(drawing_wand, &candidate, false)?[4] <= width {
+                    line = candidate;
+                } else {
+                    lines.push(std::mem::replace(&mut line, word.to_owned()));
+                }
+            }
+
+            lines.push(line);
+        }
+
+        Ok(lines)
+    }
+
+    fn addImage(&mut self, env: JNIEnv, _: JObject, other_wand: JObject) -> JNIResult<()> {
+        let r_obj = env.get_handle::<MagickWand>(other_wand)?;
+        Ok(self.add_image(&r_obj.instance)?)
+    }
+
+    fn appendAll(&mut self, env: JNIEnv, _: JObject, stack: jboolean) -> JNIResult<jobject> {
+        let wand = self.append_all(stack != 0);
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn coalesceImages(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickCoalesceImages(self.wand) },
+            "failed to coalesce images"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn optimizeImageLayers(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickOptimizeImageLayers(self.wand) },
+            "failed to optimize image layers"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn optimizeImageTransparency(&self, env: JNIEnv) -> JNIResult<jobject> {
+        // this one works in place, so do it on a copy to leave self alone like the rest of the layer ops
+        let wand = self.instance.clone();
+
+        let res = unsafe { bindings::MagickOptimizeImageTransparency(wand.wand) };
+        utils::magick_result(res, "failed to optimize image transparency")?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn magickMergeImageLayers(&self, env: JNIEnv, _: JObject, method: jint) -> JNIResult<jobject> {
+        let method = LayerMethod::try_from_int(method)?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickMergeImageLayers(self.wand, method) },
+            "failed to merge image layers"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn magickCompareImagesLayers(&self, env: JNIEnv, _: JObject, method: jint) -> JNIResult<jobject> {
+        let method = LayerMethod::try_from_int(method)?;
+
+        let wand = utils::new_wand(
+            unsafe { bindings::MagickCompareImagesLayers(self.wand, method) },
+            "failed to compare image layers"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn magickMontageImage(
+        &self,
+        env: JNIEnv,
+        _: JObject,
+        drawing_wand: JObject,
+        tile_geometry: JString,
+        thumbnail_geometry: JString,
+        mode: jint,
+        frame: JString
+    ) -> JNIResult<jobject> {
+        let drawing_wand = env.get_handle::<DrawingWand>(drawing_wand)?;
+        let tile_geometry = CString::new(env.get_jstring(tile_geometry)?)?;
+        let thumbnail_geometry = CString::new(env.get_jstring(thumbnail_geometry)?)?;
+        let mode = MontageMode::try_from_int(mode)?;
+
+        // no frame spec means no frame gets drawn
+        let frame = if frame.is_null() {
+            None
+        } else {
+            Some(CString::new(env.get_jstring(frame)?)?)
+        };
+        let frame_ptr = frame.as_ref().map_or(std::ptr::null(), |f| f.as_ptr());
+
+        let wand = utils::new_wand(
+            unsafe {
+                bindings::MagickMontageImage(
+                    self.wand,
+                    drawing_wand.wand,
+                    tile_geometry.as_ptr(),
+                    thumbnail_geometry.as_ptr(),
+                    mode,
+                    frame_ptr
+                )
+            },
+            "failed to montage images"
+        )?;
+
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn writeImages(&self, env: JNIEnv, _: JObject, path: JString, adjoin: jboolean) -> JNIResult<()> {
+        let path = env.get_jstring(path)?;
+        Ok(self.write_images(&*path, adjoin != 0)?)
+    }
+
+    fn readImageBlob(&self, env: JNIEnv, _: JObject, data: jbyteArray) -> JNIResult<()> {
+        let bytes = env.convert_byte_array(data)?;
+        Ok(self.read_image_blob(bytes)?)
+    }
+
+    fn pingImageBlob(&self, env: JNIEnv, _: JObject, data: jbyteArray) -> JNIResult<()> {
+        let bytes = env.convert_byte_array(data)?;
+        Ok(self.ping_image_blob(bytes)?)
+    }
+
+    fn compareImages(&self, env: JNIEnv, _: JObject, reference: JObject, metric: jint) -> JNIResult<jobject> {
+        let reference = env.get_handle::<MagickWand>(reference)?;
+
+        #[cfg(target_os="android")]
+        let metric = u32::try_from(metric)?;
+
+        let (distortion, r_diffImage) = self.compare_images(&reference.instance, metric);
+
+        let mut diffImage = None;
+        if r_diffImage.is_some() {
+            let wand = r_diffImage.unwrap();
+            diffImage = Some(new_from_wand!(env, wand, MagickWand));
+        }
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/Comparison")?;
+        let j_distortion = JValue::Double(distortion);
+        let j_diffImage = JValue::Object(
+            diffImage.unwrap_or(JObject::null())
+        );
+        let mid = env.get_method_id(cls, "<init>", "(DLcom/cherryleafroad/kmagick/MagickWand;)V")?;
+
+        let comparison = env.new_object_unchecked(cls, mid, &[j_distortion, j_diffImage])?;
+
+        Ok(comparison.into_inner())
+    }
+
+    fn similarityImage(&self, env: JNIEnv, _: JObject, reference: JObject, metric: jint, threshold: jdouble) -> JNIResult<jobject> {
+        let reference = env.get_handle::<MagickWand>(reference)?;
+
+        #[cfg(target_os="android")]
+        let metric = u32::try_from(metric)?;
+
+        let mut offset = bindings::RectangleInfo {
+            width: 0,
+            height: 0,
+            x: 0,
+            y: 0
+        };
+        let mut similarity = 0f64;
+
+        let r_similarityImage = unsafe {
+            bindings::MagickSimilarityImage(self.wand, reference.wand, metric, threshold, &mut offset, &mut similarity)
+        };
+
+        let wand = utils::new_wand(r_similarityImage, "failed to similarity image")?;
+        let similarityImage = new_from_wand!(env, wand, MagickWand);
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/Similarity")?;
+        let j_x = JValue::Long(i64::try_from(offset.x)?);
+        let j_y = JValue::Long(i64::try_from(offset.y)?);
+        let j_similarity = JValue::Double(similarity);
+        let j_similarityImage = JValue::Object(similarityImage);
+        let mid = env.get_method_id(cls, "<init>", "(JJDLcom/cherryleafroad/kmagick/MagickWand;)V")?;
+
+        let result = env.new_object_unchecked(cls, mid, &[j_x, j_y, j_similarity, j_similarityImage])?;
+
+        Ok(result.into_inner())
+    }
+
+    fn compositeImage(&self, env: JNIEnv, _: JObject, reference: JObject, composition_operator: jint, clip_to_self: jboolean, x: jlong, y: jlong) -> JNIResult<()> {
+        let reference = env.get_handle::<MagickWand>(reference)?;
+
+        #[cfg(target_os="android")]
+        let composition_operator = u32::try_from(composition_operator)?;
+
+        self.compose_images(&reference.instance, composition_operator, clip_to_self != 0, x as isize, y as isize)?;
+        Ok(())
+    }
+
+    fn clutImage(&self, env: JNIEnv, _: JObject, clut_wand: JObject, method: jint) -> JNIResult<()> {
+        let clut_wand = env.get_handle::<MagickWand>(clut_wand)?;
+
+        #[cfg(target_os="android")]
+        let method = u32::try_from(method)?;
+
+        self.clut_image(&clut_wand.instance, method)?;
+        Ok(())
     }
 
     fn haldClutImage(&self, env: JNIEnv, _: JObject, clut_wand: JObject) -> JNIResult<()> {
@@ -252,112 +1009,792 @@ impl MagickWand {
         Ok(self.level_image(black_point, gamma, white_point)?)
     }
 
-    fn extendImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, x: jlong, y: jlong) -> JNIResult<()> {
-        let width = usize::try_from(width)?;
-        let height = usize::try_from(height)?;
-        let x = isize::try_from(x)?;
-        let y = isize::try_from(y)?;
+    fn autoLevelImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickAutoLevelImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to auto level image")?)
+    }
+
+    fn autoGammaImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickAutoGammaImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to auto gamma image")?)
+    }
+
+    fn normalizeImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickNormalizeImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to normalize image")?)
+    }
+
+    fn equalizeImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickEqualizeImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to equalize image")?)
+    }
+
+    fn contrastStretchImage(&self, _: JNIEnv, _: JObject, black_point: jdouble, white_point: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickContrastStretchImage(self.wand, black_point, white_point) };
+        Ok(utils::magick_result(res, "failed to contrast stretch image")?)
+    }
+
+    fn linearStretchImage(&self, _: JNIEnv, _: JObject, black_point: jdouble, white_point: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickLinearStretchImage(self.wand, black_point, white_point) };
+        Ok(utils::magick_result(res, "failed to linear stretch image")?)
+    }
+
+    fn sigmoidalContrastImage(&self, _: JNIEnv, _: JObject, sharpen: jboolean, alpha: jdouble, beta: jdouble) -> JNIResult<()> {
+        let res = unsafe {
+            bindings::MagickSigmoidalContrastImage(self.wand, utils::to_magick_bool(sharpen), alpha, beta)
+        };
+        Ok(utils::magick_result(res, "failed to sigmoidal contrast image")?)
+    }
+
+    fn brightnessContrastImage(&self, _: JNIEnv, _: JObject, brightness: jdouble, contrast: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickBrightnessContrastImage(self.wand, brightness, contrast) };
+        Ok(utils::magick_result(res, "failed to brightness contrast image")?)
+    }
+
+    fn whiteBalanceImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickWhiteBalanceImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to white balance image")?)
+    }
+
+    fn claheImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, number_bins: jdouble, clip_limit: jdouble) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+
+        let res = unsafe { bindings::MagickCLAHEImage(self.wand, width, height, number_bins, clip_limit) };
+        Ok(utils::magick_result(res, "failed to clahe image")?)
+    }
+
+    fn extendImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, x: jlong, y: jlong) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        Ok(self.extend_image(width, height, x, y)?)
+    }
+
+    fn profileImage(&self, env: JNIEnv, _: JObject, name: JString, profile: jbyteArray) -> JNIResult<()> {
+        let name = env.get_jstring(name)?;
+        let bytes: Vec<u8>;
+        let profile = if !profile.is_null() {
+            bytes = env.convert_byte_array(profile)?;
+            Some(&*bytes)
+        } else {
+            None
+        };
+
+        Ok(self.profile_image(&*name, profile)?)
+    }
+
+    fn getImageProfile(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jbyteArray> {
+        let name = CString::new(env.get_jstring(name)?)?;
+
+        let mut length = 0usize;
+        let profile = unsafe { bindings::MagickGetImageProfile(self.wand, name.as_ptr(), &mut length) };
+
+        MagickWand::take_profile(env, profile, length)
+    }
+
+    fn getImageProfiles(&self, env: JNIEnv, _: JObject, pattern: JString) -> JNIResult<jobjectArray> {
+        let pattern = CString::new(env.get_jstring(pattern)?)?;
+
+        let mut number = 0usize;
+        let profiles = unsafe { bindings::MagickGetImageProfiles(self.wand, pattern.as_ptr(), &mut number) };
+
+        MagickWand::take_string_list(env, profiles, number)
+    }
+
+    fn removeImageProfile(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jbyteArray> {
+        let name = CString::new(env.get_jstring(name)?)?;
+
+        let mut length = 0usize;
+        let profile = unsafe { bindings::MagickRemoveImageProfile(self.wand, name.as_ptr(), &mut length) };
+
+        MagickWand::take_profile(env, profile, length)
+    }
+
+    fn setImageProfile(&self, env: JNIEnv, _: JObject, name: JString, profile: jbyteArray) -> JNIResult<()> {
+        let name = CString::new(env.get_jstring(name)?)?;
+        let profile = env.convert_byte_array(profile)?;
+
+        let res = unsafe {
+            bindings::MagickSetImageProfile(
+                self.wand,
+                name.as_ptr(),
+                profile.as_ptr() as *const std::os::raw::c_void,
+                profile.len()
+            )
+        };
+        Ok(utils::magick_result(res, "failed to set image profile")?)
+    }
+
+    // copies a profile imagemagick handed back into a java byte array (null for no profile), and frees it
+    #[jignore]
+    fn take_profile(env: JNIEnv, profile: *mut u8, length: usize) -> JNIResult<jbyteArray> {
+        if profile.is_null() {
+            return Ok(std::ptr::null_mut());
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(profile, length) }.to_vec();
+
+        unsafe {
+            bindings::MagickRelinquishMemory(profile as *mut std::os::raw::c_void);
+        }
+
+        let j_byte_obj = env.new_byte_array(i32::try_from(bytes.len())?)?;
+        env.set_byte_array_region(j_byte_obj, 0, bytemuck::cast_slice::<u8, i8>(&*bytes))?;
+        Ok(j_byte_obj)
+    }
+
+    // copies a string imagemagick handed back into a java string (null for no string), and frees it
+    #[jignore]
+    fn take_string(env: JNIEnv, string: *mut std::os::raw::c_char) -> JNIResult<jstring> {
+        if string.is_null() {
+            return Ok(std::ptr::null_mut());
+        }
+
+        let value = unsafe { std::ffi::CStr::from_ptr(string) }.to_string_lossy().into_owned();
+
+        unsafe {
+            bindings::MagickRelinquishMemory(string as *mut std::os::raw::c_void);
+        }
+
+        Ok(env.new_string(value)?.into_inner())
+    }
+
+    // copies a list of strings imagemagick handed back into a java string array, and frees it
+    #[jignore]
+    fn take_string_list(env: JNIEnv, list: *mut *mut std::os::raw::c_char, number: usize) -> JNIResult<jobjectArray> {
+        let mut strings = vec![];
+
+        if !list.is_null() {
+            unsafe {
+                for i in 0..number {
+                    let string = *list.add(i);
+                    strings.push(std::ffi::CStr::from_ptr(string).to_string_lossy().into_owned());
+                    bindings::MagickRelinquishMemory(string as *mut std::os::raw::c_void);
+                }
+
+                bindings::MagickRelinquishMemory(list as *mut std::os::raw::c_void);
+            }
+        }
+
+        let arr = env.new_object_array(i32::try_from(strings.len())?, "java/lang/String", JObject::null())?;
+        for (i, string) in strings.iter().enumerate() {
+            let value = env.new_string(string)?;
+            env.set_object_array_element(arr, i32::try_from(i)?, value)?;
+        }
+
+        Ok(arr)
+    }
+
+    fn blurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
+        Ok(self.blur_image(radius, sigma)?)
+    }
+
+    fn gaussianBlurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
+        Ok(self.gaussian_blur_image(radius, sigma)?)
+    }
+
+    // runs op with the image channel mask set to channel, then puts the previous mask back
+    #[jignore]
+    fn with_channel_mask<F>(&self, channel: jint, msg: &str, op: F) -> utils::Result<()>
+        where F: FnOnce() -> bindings::MagickBooleanType
+    {
+        #[cfg(target_os="android")]
+        let channel = u32::try_from(channel)?;
+
+        let previous = unsafe { bindings::MagickSetImageChannelMask(self.wand, channel) };
+        let res = op();
+        unsafe { bindings::MagickSetImageChannelMask(self.wand, previous) };
+
+        utils::magick_result(res, msg)
+    }
+
+    fn magickMotionBlurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble, angle: jdouble, channel: jint) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to motion blur image", || unsafe {
+            bindings::MagickMotionBlurImage(self.wand, radius, sigma, angle)
+        })?)
+    }
+
+    fn magickRotationalBlurImage(&self, _: JNIEnv, _: JObject, angle: jdouble, channel: jint) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to rotational blur image", || unsafe {
+            bindings::MagickRotationalBlurImage(self.wand, angle)
+        })?)
+    }
+
+    fn magickSelectiveBlurImage(
+        &self,
+        _: JNIEnv,
+        _: JObject,
+        radius: jdouble,
+        sigma: jdouble,
+        threshold: jdouble,
+        channel: jint
+    ) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to selective blur image", || unsafe {
+            bindings::MagickSelectiveBlurImage(self.wand, radius, sigma, threshold)
+        })?)
+    }
+
+    fn magickAdaptiveBlurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble, channel: jint) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to adaptive blur image", || unsafe {
+            bindings::MagickAdaptiveBlurImage(self.wand, radius, sigma)
+        })?)
+    }
+
+    fn adaptiveResizeImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        Ok(self.adaptive_resize_image(width, height)?)
+    }
+
+    fn rotateImage(&self, env: JNIEnv, _: JObject, background: JObject, degrees: jdouble) -> JNIResult<()> {
+        let background = env.get_handle::<PixelWand>(background)?;
+        Ok(self.rotate_image(&background.instance, degrees)?)
+    }
+
+    fn trimImage(&self, _: JNIEnv, _: JObject, fuzz: jdouble) -> JNIResult<()> {
+        Ok(self.trim_image(fuzz)?)
+    }
+
+    fn getImageBoundingBox(&self, env: JNIEnv, _: JObject, fuzz: jdouble) -> JNIResult<jobject> {
+        let image = unsafe { bindings::GetImageFromMagickWand(self.wand) };
+        if image.is_null() {
+            return utils::runtime_exception("wand contains no images");
+        }
+
+        // the same bounds trimImage would crop to, but leave the image alone (fuzz included)
+        let previous_fuzz = unsafe { bindings::MagickGetImageFuzz(self.wand) };
+        let res = unsafe { bindings::MagickSetImageFuzz(self.wand, fuzz) };
+        utils::magick_result(res, "failed to set image fuzz")?;
+
+        let (region, res) = unsafe {
+            let exception = bindings::AcquireExceptionInfo();
+            let region = bindings::GetImageBoundingBox(image, exception);
+            (region, utils::take_exception(exception, "failed to get image bounding box"))
+        };
+
+        let fuzz_res = unsafe { bindings::MagickSetImageFuzz(self.wand, previous_fuzz) };
+        res?;
+        utils::magick_result(fuzz_res, "failed to restore image fuzz")?;
+
+        MagickWand::new_page_geometry(env, &region)
+    }
+
+    fn deskewImage(&self, _: JNIEnv, _: JObject, threshold: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickDeskewImage(self.wand, threshold) };
+        Ok(utils::magick_result(res, "failed to deskew image")?)
+    }
+
+    fn resetImagePage(&self, env: JNIEnv, _: JObject, page_geometry: JString) -> JNIResult<()> {
+        let page_geometry = env.get_jstring(page_geometry)?;
+        Ok(self.reset_image_page(&*page_geometry)?)
+    }
+
+    fn getImageProperty(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jstring> {
+        let name = env.get_jstring(name)?;
+        let prop = self.get_image_property(&*name)?;
+        Ok(env.new_string(&*prop)?.into_inner())
+    }
+
+    fn setImageProperty(&self, env: JNIEnv, _: JObject, name: JString, value: JString) -> JNIResult<()> {
+        let name = env.get_jstring(name)?;
+        let value = env.get_jstring(value)?;
+        Ok(self.set_image_property(&*name, &*value)?)
+    }
+
+    fn getImageProperties(&self, env: JNIEnv, _: JObject, pattern: JString) -> JNIResult<jobjectArray> {
+        let pattern = CString::new(env.get_jstring(pattern)?)?;
+
+        let mut number = 0usize;
+        let properties = unsafe { bindings::MagickGetImageProperties(self.wand, pattern.as_ptr(), &mut number) };
+
+        MagickWand::take_string_list(env, properties, number)
+    }
+
+    fn deleteImageProperty(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jboolean> {
+        let name = CString::new(env.get_jstring(name)?)?;
+
+        // false only means there was nothing to delete
+        let res = unsafe { bindings::MagickDeleteImageProperty(self.wand, name.as_ptr()) };
+        Ok((res == bindings::MagickBooleanType_MagickTrue) as jboolean)
+    }
+
+    fn getImageArtifact(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jstring> {
+        let name = CString::new(env.get_jstring(name)?)?;
+
+        let value = unsafe { bindings::MagickGetImageArtifact(self.wand, name.as_ptr()) };
+        MagickWand::take_string(env, value)
+    }
+
+    fn getImageArtifacts(&self, env: JNIEnv, _: JObject, pattern: JString) -> JNIResult<jobjectArray> {
+        let pattern = CString::new(env.get_jstring(pattern)?)?;
+
+        let mut number = 0usize;
+        let artifacts = unsafe { bindings::MagickGetImageArtifacts(self.wand, pattern.as_ptr(), &mut number) };
+
+        MagickWand::take_string_list(env, artifacts, number)
+    }
+
+    fn setImageArtifact(&self, env: JNIEnv, _: JObject, name: JString, value: JString) -> JNIResult<()> {
+        let name = CString::new(env.get_jstring(name)?)?;
+        let value = CString::new(env.get_jstring(value)?)?;
+
+        let res = unsafe { bindings::MagickSetImageArtifact(self.wand, name.as_ptr(), value.as_ptr()) };
+        Ok(utils::magick_result(res, "failed to set image artifact")?)
+    }
+
+    fn deleteImageArtifact(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jboolean> {
+        let name = CString::new(env.get_jstring(name)?)?;
+
+        // false only means there was nothing to delete
+        let res = unsafe { bindings::MagickDeleteImageArtifact(self.wand, name.as_ptr()) };
+        Ok((res == bindings::MagickBooleanType_MagickTrue) as jboolean)
+    }
+
+    fn getImagePixelColor(&self, env: JNIEnv, _: JObject, x: jlong, y: jlong) -> JNIResult<jobject> {
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+        let wand = self.get_image_pixel_color(x, y);
+
+        if wand.is_some() {
+            Ok(new_from_wand!(env, wand.unwrap(), PixelWand).into_inner())
+        } else {
+            Ok(std::ptr::null_mut())
+        }
+    }
+
+    fn setSamplingFactors(&self, env: JNIEnv, _: JObject, sampling_factors: jdoubleArray) -> JNIResult<()> {
+        let buf: &mut [f64] = &mut [];
+        env.get_double_array_region(sampling_factors, 0, buf)?;
+        Ok(self.set_sampling_factors(buf)?)
+    }
+
+    fn getImageHistogram(&self, env: JNIEnv) -> JNIResult<jobjectArray> {
+        let wands = self.get_image_histogram();
+
+        if wands.is_some() {
+            let mut wands = wands.unwrap();
+            let obj = env.new_object_array(
+                i32::try_from(wands.len())?,
+                "com/cherryleafroad/kmagick/PixelWand",
+                JObject::null()
+            )?;
+
+            for num in 0..wands.len() {
+                let wand = wands.remove(0);
+                let wand = new_from_wand!(env, wand, PixelWand);
+                env.set_object_array_element(obj, num as i32, wand)?;
+            }
+
+            Ok(obj)
+        } else {
+            Ok(std::ptr::null_mut())
+        }
+    }
+
+    // only the channels the current image actually has, the same as identify. meta (extra) channels
+    // have no PixelChannel to be reported as, so they're left out
+    #[jignore]
+    fn get_image_channels(&self) -> utils::Result<Vec<bindings::PixelChannel>> {
+        let image = unsafe { bindings::GetImageFromMagickWand(self.wand) };
+        if image.is_null() {
+            return utils::runtime_exception("wand contains no images");
+        }
+
+        let mut channels = vec![];
+        unsafe {
+            let channel_map = (*image).channel_map;
+            for i in 0..(*image).number_channels {
+                let channel = (*channel_map.add(i)).channel;
+                if channel > bindings::PixelChannel_CompositeMaskPixelChannel {
+                    continue;
+                }
+
+                if (*channel_map.add(channel as usize)).traits != bindings::PixelTrait_UndefinedPixelTrait {
+                    channels.push(channel);
+                }
+            }
+        }
+
+        Ok(channels)
+    }
+
+    fn getImageStatistics(&self, env: JNIEnv) -> JNIResult<jobjectArray> {
+        let mut channels = self.get_image_channels()?;
+        channels.push(bindings::PixelChannel_CompositePixelChannel);
+
+        let statistics = unsafe { bindings::MagickGetImageStatistics(self.wand) };
+        if statistics.is_null() {
+            return utils::runtime_exception("failed to get image statistics");
+        }
+
+        let res = self.new_channel_statistics_array(env, statistics, &channels);
+
+        unsafe {
+            bindings::MagickRelinquishMemory(statistics as *mut std::os::raw::c_void);
+        }
+
+        res
+    }
+
+    #[jignore]
+    fn new_channel_statistics_array(
+        &self,
+        env: JNIEnv,
+        statistics: *const bindings::ChannelStatistics,
+        channels: &[bindings::PixelChannel]
+    ) -> JNIResult<jobjectArray> {
+        let cls = env.find_class("com/cherryleafroad/kmagick/ChannelStatistics")?;
+        let mid = env.get_method_id(cls, "<init>", "(Lcom/cherryleafroad/kmagick/PixelChannel;JDDDDDDDDD)V")?;
+
+        let arr = env.new_object_array(i32::try_from(channels.len())?, cls, JObject::null())?;
+
+        for (i, &channel) in channels.iter().enumerate() {
+            let stats = unsafe { &*statistics.add(channel as usize) };
+
+            let j_channel = JValue::Object(JObject::from(to_jenum!(env, PixelChannel, channel)));
+            let args = [
+                j_channel,
+                JValue::Long(i64::try_from(stats.depth)?),
+                JValue::Double(stats.area),
+                JValue::Double(stats.minima),
+                JValue::Double(stats.maxima),
+                JValue::Double(stats.mean),
+                JValue::Double(stats.standard_deviation),
+                JValue::Double(stats.variance),
+                JValue::Double(stats.kurtosis),
+                JValue::Double(stats.skewness),
+                JValue::Double(stats.entropy)
+            ];
+
+            let obj = env.new_object_unchecked(cls, mid, &args)?;
+            env.set_object_array_element(arr, i as jsize, obj)?;
+        }
+
+        Ok(arr)
+    }
+
+    fn getImageRange(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let mut minima = 0f64;
+        let mut maxima = 0f64;
+
+        let res = unsafe {
+            bindings::MagickGetImageRange(self.wand, &mut minima, &mut maxima)
+        };
+        utils::magick_result(res, "failed to get image range")?;
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/ImageRange")?;
+        let mid = env.get_method_id(cls, "<init>", "(DD)V")?;
+        Ok(env.new_object_unchecked(cls, mid, &[JValue::Double(minima), JValue::Double(maxima)])?.into_inner())
+    }
+
+    fn getImageMean(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let mut mean = 0f64;
+        let mut standard_deviation = 0f64;
+
+        let res = unsafe {
+            bindings::MagickGetImageMean(self.wand, &mut mean, &mut standard_deviation)
+        };
+        utils::magick_result(res, "failed to get image mean")?;
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/ImageMean")?;
+        let mid = env.get_method_id(cls, "<init>", "(DD)V")?;
+        Ok(env.new_object_unchecked(cls, mid, &[JValue::Double(mean), JValue::Double(standard_deviation)])?.into_inner())
+    }
+
+    fn getImageKurtosis(&self, env: JNIEnv) -> JNIResult<jobject> {
+        let mut kurtosis = 0f64;
+        let mut skewness = 0f64;
+
+        let res = unsafe {
+            bindings::MagickGetImageKurtosis(self.wand, &mut kurtosis, &mut skewness)
+        };
+        utils::magick_result(res, "failed to get image kurtosis")?;
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/ImageKurtosis")?;
+        let mid = env.get_method_id(cls, "<init>", "(DD)V")?;
+        Ok(env.new_object_unchecked(cls, mid, &[JValue::Double(kurtosis), JValue::Double(skewness)])?.into_inner())
+    }
+
+    fn getImageEntropy(&self) -> JNIResult<jdouble> {
+        let mut entropy = 0f64;
+
+        let res = unsafe {
+            bindings::MagickGetImageEntropy(self.wand, &mut entropy)
+        };
+        utils::magick_result(res, "failed to get image entropy")?;
+
+        Ok(entropy)
+    }
+
+    fn getImageSignature(&self, env: JNIEnv) -> JNIResult<jstring> {
+        let signature = unsafe { bindings::MagickGetImageSignature(self.wand) };
+        if signature.is_null() {
+            return utils::runtime_exception("failed to get image signature");
+        }
+
+        let res = unsafe { std::ffi::CStr::from_ptr(signature) }.to_string_lossy().into_owned();
+
+        unsafe {
+            bindings::MagickRelinquishMemory(signature as *mut std::os::raw::c_void);
+        }
+
+        Ok(env.new_string(&*res)?.into_inner())
+    }
+
+    // the perceptual hash of every channel in the current image, each one flattened
+    // to all the moments of the first colorspace followed by the moments of the next one
+    #[jignore]
+    fn get_perceptual_hash(&self, channels: &[bindings::PixelChannel]) -> utils::Result<Vec<Vec<f64>>> {
+        let hash = unsafe {
+            let image = bindings::GetImageFromMagickWand(self.wand);
+            if image.is_null() {
+                return utils::runtime_exception("wand contains no images");
+            }
+
+            let exception = bindings::AcquireExceptionInfo();
+            let hash = bindings::GetImagePerceptualHash(image, exception);
+            bindings::DestroyExceptionInfo(exception);
+            hash
+        };
+
+        if hash.is_null() {
+            return utils::runtime_exception("failed to get image perceptual hash");
+        }
+
+        // only the first MaximumNumberOfPerceptualHashes moments of each colorspace are filled in
+        let moments = bindings::MaximumNumberOfPerceptualHashes as usize;
+        let res = channels.iter().map(|&channel| {
+            let channel_hash = unsafe { &*hash.add(channel as usize) };
+
+            channel_hash.phash[..channel_hash.number_colorspaces]
+                .iter()
+                .flat_map(|colorspace| colorspace[..moments].iter().copied())
+                .collect::<Vec<_>>()
+        }).collect();
+
+        unsafe {
+            bindings::RelinquishMagickMemory(hash as *mut std::os::raw::c_void);
+        }
+
+        Ok(res)
+    }
+
+    fn getImagePerceptualHash(&self, env: JNIEnv) -> JNIResult<jobjectArray> {
+        let mut channels = self.get_image_channels()?;
+        channels.push(bindings::PixelChannel_CompositePixelChannel);
+
+        let hashes = self.get_perceptual_hash(&channels)?;
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/ChannelPerceptualHash")?;
+        let mid = env.get_method_id(cls, "<init>", "(Lcom/cherryleafroad/kmagick/PixelChannel;[D)V")?;
+
+        let arr = env.new_object_array(i32::try_from(channels.len())?, cls, JObject::null())?;
+
+        for (i, (&channel, hash)) in channels.iter().zip(hashes.iter()).enumerate() {
+            let j_channel = JValue::Object(JObject::from(to_jenum!(env, PixelChannel, channel)));
+
+            let j_hash = env.new_double_array(i32::try_from(hash.len())?)?;
+            env.set_double_array_region(j_hash, 0, hash)?;
+
+            let obj = env.new_object_unchecked(cls, mid, &[j_channel, JValue::Object(JObject::from(j_hash))])?;
+            env.set_object_array_element(arr, i as jsize, obj)?;
+        }
+
+        Ok(arr)
+    }
+
+    // sum of squared differences between the hashes, over the channels both images have,
+    // same as the PerceptualHash metric
+    fn perceptualHashDistance(&self, env: JNIEnv, _: JObject, other: JObject) -> JNIResult<jdouble> {
+        let other = env.get_handle::<MagickWand>(other)?;
+
+        let other_channels = other.get_image_channels()?;
+        let channels = self.get_image_channels()?
+            .into_iter()
+            .filter(|channel| other_channels.contains(channel))
+            .collect::<Vec<_>>();
+
+        if channels.is_empty() {
+            return utils::runtime_exception("images have no channels in common");
+        }
+
+        let hash = self.get_perceptual_hash(&channels)?;
+        let other_hash = other.get_perceptual_hash(&channels)?;
+
+        let distance: f64 = hash.iter().zip(other_hash.iter())
+            .flat_map(|(a, b)| a.iter().zip(b.iter()))
+            .map(|(a, b)| (b - a) * (b - a))
+            .sum();
+
+        Ok(distance)
+    }
+
+    fn sharpenImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
+        Ok(self.sharpen_image(radius, sigma)?)
+    }
+
+    fn magickAdaptiveSharpenImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble, channel: jint) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to adaptive sharpen image", || unsafe {
+            bindings::MagickAdaptiveSharpenImage(self.wand, radius, sigma)
+        })?)
+    }
 
-        Ok(self.extend_image(width, height, x, y)?)
+    fn magickUnsharpMaskImage(
+        &self,
+        _: JNIEnv,
+        _: JObject,
+        radius: jdouble,
+        sigma: jdouble,
+        gain: jdouble,
+        threshold: jdouble,
+        channel: jint
+    ) -> JNIResult<()> {
+        Ok(self.with_channel_mask(channel, "failed to unsharp mask image", || unsafe {
+            bindings::MagickUnsharpMaskImage(self.wand, radius, sigma, gain, threshold)
+        })?)
     }
 
-    fn profileImage(&self, env: JNIEnv, _: JObject, name: JString, profile: jbyteArray) -> JNIResult<()> {
-        let name = env.get_jstring(name)?;
-        let bytes: Vec<u8>;
-        let profile = if !profile.is_null() {
-            bytes = env.convert_byte_array(profile)?;
-            Some(&*bytes)
-        } else {
-            None
+    fn magickMorphologyImage(&self, env: JNIEnv, _: JObject, method: jint, iterations: jlong, kernel: JObject) -> JNIResult<()> {
+        let method = MorphologyMethod::try_from_int(method)?;
+        let iterations = isize::try_from(iterations)?;
+        let kernel = env.get_handle::<KernelInfo>(kernel)?;
+
+        let res = unsafe {
+            bindings::MagickMorphologyImage(self.wand, method, iterations, kernel.instance)
         };
 
-        Ok(self.profile_image(&*name, profile)?)
+        Ok(utils::magick_result(res, "failed to apply morphology to image")?)
     }
 
-    fn blurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
-        Ok(self.blur_image(radius, sigma)?)
-    }
+    fn convolveImage(&self, env: JNIEnv, _: JObject, kernel: JObject) -> JNIResult<()> {
+        let kernel = env.get_handle::<KernelInfo>(kernel)?;
 
-    fn gaussianBlurImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
-        Ok(self.gaussian_blur_image(radius, sigma)?)
+        let res = unsafe {
+            bindings::MagickConvolveImage(self.wand, kernel.instance)
+        };
+
+        Ok(utils::magick_result(res, "failed to convolve image")?)
     }
 
+    fn colorMatrixImage(&self, env: JNIEnv, _: JObject, color_matrix: JObject) -> JNIResult<()> {
+        let color_matrix = env.get_handle::<KernelInfo>(color_matrix)?;
 
-    fn adaptiveResizeImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong) -> JNIResult<()> {
-        let width = usize::try_from(width)?;
-        let height = usize::try_from(height)?;
-        Ok(self.adaptive_resize_image(width, height)?)
-    }
+        let res = unsafe {
+            bindings::MagickColorMatrixImage(self.wand, color_matrix.instance)
+        };
 
-    fn rotateImage(&self, env: JNIEnv, _: JObject, background: JObject, degrees: jdouble) -> JNIResult<()> {
-        let background = env.get_handle::<PixelWand>(background)?;
-        Ok(self.rotate_image(&background.instance, degrees)?)
+        Ok(utils::magick_result(res, "failed to apply color matrix to image")?)
     }
 
-    fn trimImage(&self, _: JNIEnv, _: JObject, fuzz: jdouble) -> JNIResult<()> {
-        Ok(self.trim_image(fuzz)?)
+    fn cannyEdgeImage(
+        &self,
+        _: JNIEnv,
+        _: JObject,
+        radius: jdouble,
+        sigma: jdouble,
+        lower_percent: jdouble,
+        upper_percent: jdouble
+    ) -> JNIResult<()> {
+        let res = unsafe {
+            bindings::MagickCannyEdgeImage(self.wand, radius, sigma, lower_percent, upper_percent)
+        };
+        Ok(utils::magick_result(res, "failed to canny edge image")?)
     }
 
-    fn resetImagePage(&self, env: JNIEnv, _: JObject, page_geometry: JString) -> JNIResult<()> {
-        let page_geometry = env.get_jstring(page_geometry)?;
-        Ok(self.reset_image_page(&*page_geometry)?)
-    }
+    fn houghLineImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, threshold: jlong) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let threshold = usize::try_from(threshold)?;
 
-    fn getImageProperty(&self, env: JNIEnv, _: JObject, name: JString) -> JNIResult<jstring> {
-        let name = env.get_jstring(name)?;
-        let prop = self.get_image_property(&*name)?;
-        Ok(env.new_string(&*prop)?.into_inner())
+        let res = unsafe { bindings::MagickHoughLineImage(self.wand, width, height, threshold) };
+        Ok(utils::magick_result(res, "failed to hough line image")?)
     }
 
-    fn setImageProperty(&self, env: JNIEnv, _: JObject, name: JString, value: JString) -> JNIResult<()> {
-        let name = env.get_jstring(name)?;
-        let value = env.get_jstring(value)?;
-        Ok(self.set_image_property(&*name, &*value)?)
-    }
+    fn connectedComponentsImage(&self, env: JNIEnv, _: JObject, connectivity: jlong) -> JNIResult<jobjectArray> {
+        let connectivity = usize::try_from(connectivity)?;
 
-    fn getImagePixelColor(&self, env: JNIEnv, _: JObject, x: jlong, y: jlong) -> JNIResult<jobject> {
-        let x = isize::try_from(x)?;
-        let y = isize::try_from(y)?;
-        let wand = self.get_image_pixel_color(x, y);
+        let mut objects: *mut bindings::CCObjectInfo = std::ptr::null_mut();
+        let res = unsafe {
+            bindings::MagickConnectedComponentsImage(self.wand, connectivity, &mut objects)
+        };
+        utils::magick_result(res, "failed to connected components image")?;
 
-        if wand.is_some() {
-            Ok(new_from_wand!(env, wand.unwrap(), PixelWand).into_inner())
-        } else {
-            Ok(std::ptr::null_mut())
+        if objects.is_null() {
+            return utils::runtime_exception("failed to get connected components");
         }
-    }
 
-    fn setSamplingFactors(&self, env: JNIEnv, _: JObject, sampling_factors: jdoubleArray) -> JNIResult<()> {
-        let buf: &mut [f64] = &mut [];
-        env.get_double_array_region(sampling_factors, 0, buf)?;
-        Ok(self.set_sampling_factors(buf)?)
-    }
+        // the image is now the labeled image, with one color per object
+        let number_objects = unsafe { (*bindings::GetImageFromMagickWand(self.wand)).colors };
 
-    fn getImageHistogram(&self, env: JNIEnv) -> JNIResult<jobjectArray> {
-        let wands = self.get_image_histogram();
+        // unused labels are left in with an area of 0, the same as what -connected-components skips
+        let components = unsafe { std::slice::from_raw_parts(objects, number_objects) }
+            .iter()
+            .filter(|o| o.area >= bindings::MagickEpsilon)
+            .collect::<Vec<_>>();
 
-        if wands.is_some() {
-            let mut wands = wands.unwrap();
-            let obj = env.new_object_array(
-                i32::try_from(wands.len())?,
-                "com/cherryleafroad/kmagick/PixelWand",
-                JObject::null()
-            )?;
+        let res = self.new_connected_components_array(env, &components);
 
-            for num in 0..wands.len() {
-                let wand = wands.remove(0);
-                let wand = new_from_wand!(env, wand, PixelWand);
-                env.set_object_array_element(obj, num as i32, wand)?;
+        unsafe {
+            bindings::RelinquishMagickMemory(objects as *mut std::os::raw::c_void);
+        }
+
+        res
+    }
+
+    #[jignore]
+    fn new_connected_components_array(&self, env: JNIEnv, components: &[&bindings::CCObjectInfo]) -> JNIResult<jobjectArray> {
+        let cls = env.find_class("com/cherryleafroad/kmagick/ConnectedComponent")?;
+        let mid = env.get_method_id(
+            cls,
+            "<init>",
+            "(JLcom/cherryleafroad/kmagick/PageGeometry;DDDLcom/cherryleafroad/kmagick/PixelWand;)V"
+        )?;
+
+        let arr = env.new_object_array(i32::try_from(components.len())?, cls, JObject::null())?;
+
+        // ids of the pixel wands handed out so far, so they can be destroyed again if a later element fails
+        let mut ids = vec![];
+
+        // there can be thousands of these, so give each one its own local frame
+        let res = (|| -> utils::Result<()> {
+            for (i, component) in components.iter().enumerate() {
+                utils::with_local_frame(env, 16, || {
+                    let bounding_box = JObject::from(MagickWand::new_page_geometry(env, &component.bounding_box)?);
+
+                    let pixel_wand = magick_rust::PixelWand::new();
+                    unsafe {
+                        bindings::PixelSetPixelColor(pixel_wand.wand, &component.color);
+                    }
+                    let color = new_from_wand!(env, pixel_wand, PixelWand);
+                    ids.push(env.get_handle::<PixelWand>(color)?.id);
+
+                    let obj = env.new_object_unchecked(cls, mid, &[
+                        JValue::Long(i64::try_from(component.id)?),
+                        JValue::Object(bounding_box),
+                        JValue::Double(component.centroid.x),
+                        JValue::Double(component.centroid.y),
+                        JValue::Double(component.area),
+                        JValue::Object(color)
+                    ])?;
+
+                    env.set_object_array_element(arr, i32::try_from(i)?, obj)?;
+                    Ok(())
+                })?;
             }
 
-            Ok(obj)
-        } else {
-            Ok(std::ptr::null_mut())
+            Ok(())
+        })();
+
+        // the array never makes it back to java, so nothing else could ever destroy them
+        if res.is_err() {
+            crate::cache::destroy_ids::<PixelWand>(env, &*crate::cache::PIXELWAND_CACHE, &ids)?;
         }
-    }
+        res?;
 
-    fn sharpenImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
-        Ok(self.sharpen_image(radius, sigma)?)
+        Ok(arr)
     }
 
     fn setBackgroundColor(&self, env: JNIEnv, _: JObject, pixel_wand: JObject) -> JNIResult<()> {
@@ -440,6 +1877,172 @@ impl MagickWand {
         Ok(())
     }
 
+    fn parseMetaGeometry(&self, env: JNIEnv, _: JObject, geometry: JString) -> JNIResult<jobject> {
+        let geometry = env.get_jstring(geometry)?;
+        let region = self.parse_meta_geometry(&*geometry)?;
+
+        MagickWand::new_page_geometry(env, &region)
+    }
+
+    fn parseGravityGeometry(&self, env: JNIEnv, _: JObject, geometry: JString) -> JNIResult<jobject> {
+        let geometry = env.get_jstring(geometry)?;
+        let region = self.parse_gravity_geometry(&*geometry)?;
+
+        MagickWand::new_page_geometry(env, &region)
+    }
+
+    fn transformImage(&self, env: JNIEnv, _: JObject, crop: JString, resize: JString) -> JNIResult<()> {
+        if !crop.is_null() {
+            let crop = env.get_jstring(crop)?;
+            let region = self.parse_gravity_geometry(&*crop)?;
+
+            self.crop_image(region.width, region.height, region.x, region.y)?;
+        }
+
+        if !resize.is_null() {
+            let resize = env.get_jstring(resize)?;
+            let region = self.parse_meta_geometry(&*resize)?;
+
+            // flags like `>` and `<` can leave the size alone, so don't resample for nothing
+            if region.width != self.get_image_width() || region.height != self.get_image_height() {
+                let res = unsafe {
+                    bindings::MagickResizeImage(self.wand, region.width, region.height, bindings::FilterType_UndefinedFilter)
+                };
+                utils::magick_result(res, "failed to resize image")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // resolves a resize geometry (with its ^ ! < > % @ flags) against the current image size
+    #[jignore]
+    fn parse_meta_geometry(&self, geometry: &str) -> utils::Result<bindings::RectangleInfo> {
+        let c_geometry = CString::new(geometry)?;
+
+        let mut region = bindings::RectangleInfo {
+            width: self.get_image_width(),
+            height: self.get_image_height(),
+            x: 0,
+            y: 0
+        };
+
+        let flags = unsafe {
+            bindings::ParseMetaGeometry(
+                c_geometry.as_ptr(),
+                &mut region.x,
+                &mut region.y,
+                &mut region.width,
+                &mut region.height
+            )
+        };
+
+        if flags == bindings::GeometryFlags_NoValue as bindings::MagickStatusType {
+            return utils::runtime_exception(format!("invalid geometry `{geometry}`"));
+        }
+
+        Ok(region)
+    }
+
+    // resolves a region geometry against the current image size, respecting the image gravity
+    #[jignore]
+    fn parse_gravity_geometry(&self, geometry: &str) -> utils::Result<bindings::RectangleInfo> {
+        let image = unsafe { bindings::GetImageFromMagickWand(self.wand) };
+        if image.is_null() {
+            return utils::runtime_exception("wand contains no images");
+        }
+
+        let c_geometry = CString::new(geometry)?;
+
+        let mut region = bindings::RectangleInfo {
+            width: 0,
+            height: 0,
+            x: 0,
+            y: 0
+        };
+
+        let flags = unsafe {
+            let exception = bindings::AcquireExceptionInfo();
+            let flags = bindings::ParseGravityGeometry(image, c_geometry.as_ptr(), &mut region, exception);
+            bindings::DestroyExceptionInfo(exception);
+            flags
+        };
+
+        if flags == bindings::GeometryFlags_NoValue as bindings::MagickStatusType {
+            return utils::runtime_exception(format!("invalid geometry `{geometry}`"));
+        }
+
+        Ok(region)
+    }
+
+    #[jignore]
+    fn new_page_geometry(env: JNIEnv, region: &bindings::RectangleInfo) -> JNIResult<jobject> {
+        let width = JValue::Long(i64::try_from(region.width)?);
+        let height = JValue::Long(i64::try_from(region.height)?);
+        let x = JValue::Long(i64::try_from(region.x)?);
+        let y = JValue::Long(i64::try_from(region.y)?);
+
+        let cls = env.find_class("com/cherryleafroad/kmagick/PageGeometry")?;
+        let mid = env.get_method_id(cls, "<init>", "(JJJJ)V")?;
+        Ok(env.new_object_unchecked(cls, mid, &[width, height, x, y])?.into_inner())
+    }
+
+    fn getImageRegion(&self, env: JNIEnv, _: JObject, width: jlong, height: jlong, x: jlong, y: jlong) -> JNIResult<jobject> {
+        let wand = self.get_image_region(width, height, x, y)?;
+        Ok(new_from_wand!(env, wand, MagickWand).into_inner())
+    }
+
+    fn getImageRegions(&self, env: JNIEnv, _: JObject, regions: jobjectArray) -> JNIResult<jobjectArray> {
+        let len = env.get_array_length(regions)?;
+        let arr = env.new_object_array(len, "com/cherryleafroad/kmagick/MagickWand", JObject::null())?;
+
+        // ids of the wands handed out so far, so they can be destroyed again if a later region fails
+        let mut ids = vec![];
+
+        // there can be hundreds of these, so give each one its own local frame
+        let res = (|| -> utils::Result<()> {
+            for i in 0..len {
+                utils::with_local_frame(env, 16, || {
+                    let region = env.get_object_array_element(regions, i)?;
+                    let width = env.get_field(region, "width", "J")?.j()?;
+                    let height = env.get_field(region, "height", "J")?.j()?;
+                    let x = env.get_field(region, "x", "J")?.j()?;
+                    let y = env.get_field(region, "y", "J")?.j()?;
+
+                    let wand = self.get_image_region(width, height, x, y)?;
+                    let wand = new_from_wand!(env, wand, MagickWand);
+                    ids.push(env.get_handle::<MagickWand>(wand)?.id);
+
+                    env.set_object_array_element(arr, i, wand)?;
+                    Ok(())
+                })?;
+            }
+
+            Ok(())
+        })();
+
+        // the array never makes it back to java, so nothing else could ever destroy them
+        if res.is_err() {
+            crate::cache::destroy_ids::<MagickWand>(env, &*crate::cache::MAGICKWAND_CACHE, &ids)?;
+        }
+        res?;
+
+        Ok(arr)
+    }
+
+    #[jignore]
+    fn get_image_region(&self, width: jlong, height: jlong, x: jlong, y: jlong) -> utils::Result<magick_rust::MagickWand> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        utils::new_wand(
+            unsafe { bindings::MagickGetImageRegion(self.wand, width, height, x, y) },
+            "failed to get image region"
+        )
+    }
+
     fn cropImage(
         &self,
         _: JNIEnv,
@@ -496,6 +2099,63 @@ impl MagickWand {
         Ok(self.liquid_rescale_image(width, height, delta_x, rigidity)?)
     }
 
+    fn magickDistortImage(&self, env: JNIEnv, _: JObject, method: jint, args: jdoubleArray, best_fit: jboolean) -> JNIResult<()> {
+        let method = DistortMethod::try_from_int(method)?;
+        let args = utils::get_double_array(env, args)?;
+
+        let res = unsafe {
+            bindings::MagickDistortImage(self.wand, method, args.len(), args.as_ptr(), utils::to_magick_bool(best_fit))
+        };
+
+        Ok(utils::magick_result(res, "failed to distort image")?)
+    }
+
+    fn perspectiveCorrect(&self, env: JNIEnv, _: JObject, corners: jdoubleArray, out_width: jlong, out_height: jlong) -> JNIResult<()> {
+        let corners = utils::get_double_array(env, corners)?;
+        if corners.len() != 8 {
+            return utils::runtime_exception(format!("expected 8 corner values, got {}", corners.len()));
+        }
+
+        let width = usize::try_from(out_width)? as f64;
+        let height = usize::try_from(out_height)? as f64;
+
+        // top left, top right, bottom right, bottom left mapped onto the corners of the output
+        let targets = [0.0, 0.0, width, 0.0, width, height, 0.0, height];
+        let args = corners.chunks(2)
+            .zip(targets.chunks(2))
+            .flat_map(|(src, dst)| [src[0], src[1], dst[0], dst[1]])
+            .collect::<Vec<_>>();
+
+        let viewport_key = CString::new("distort:viewport")?;
+        let viewport = CString::new(format!("{out_width}x{out_height}+0+0"))?;
+
+        let res = unsafe {
+            // the caller may have set their own viewport, put it back afterwards
+            let previous = bindings::MagickGetImageArtifact(self.wand, viewport_key.as_ptr());
+
+            bindings::MagickSetImageArtifact(self.wand, viewport_key.as_ptr(), viewport.as_ptr());
+
+            let res = bindings::MagickDistortImage(
+                self.wand,
+                bindings::DistortMethod_PerspectiveDistortion,
+                args.len(),
+                args.as_ptr(),
+                bindings::MagickBooleanType_MagickFalse
+            );
+
+            if previous.is_null() {
+                bindings::MagickDeleteImageArtifact(self.wand, viewport_key.as_ptr());
+            } else {
+                bindings::MagickSetImageArtifact(self.wand, viewport_key.as_ptr(), previous);
+                bindings::MagickRelinquishMemory(previous as *mut std::os::raw::c_void);
+            }
+
+            res
+        };
+
+        Ok(utils::magick_result(res, "failed to perspective correct image")?)
+    }
+
     fn implode(&self, _: JNIEnv, _: JObject, amount: jdouble, method: jint) -> JNIResult<()> {
         #[cfg(target_os="android")]
         let method = u32::try_from(method)?;
@@ -503,6 +2163,156 @@ impl MagickWand {
         Ok(self.instance.implode(amount, method)?)
     }
 
+    fn thresholdImage(&self, _: JNIEnv, _: JObject, threshold: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickThresholdImage(self.wand, threshold) };
+        Ok(utils::magick_result(res, "failed to threshold image")?)
+    }
+
+    fn adaptiveThresholdImage(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong, bias: jdouble) -> JNIResult<()> {
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+
+        let res = unsafe { bindings::MagickAdaptiveThresholdImage(self.wand, width, height, bias) };
+        Ok(utils::magick_result(res, "failed to adaptive threshold image")?)
+    }
+
+    fn blackThresholdImage(&self, env: JNIEnv, _: JObject, threshold: JObject) -> JNIResult<()> {
+        let threshold = env.get_handle::<PixelWand>(threshold)?;
+
+        let res = unsafe { bindings::MagickBlackThresholdImage(self.wand, threshold.wand) };
+        Ok(utils::magick_result(res, "failed to black threshold image")?)
+    }
+
+    fn whiteThresholdImage(&self, env: JNIEnv, _: JObject, threshold: JObject) -> JNIResult<()> {
+        let threshold = env.get_handle::<PixelWand>(threshold)?;
+
+        let res = unsafe { bindings::MagickWhiteThresholdImage(self.wand, threshold.wand) };
+        Ok(utils::magick_result(res, "failed to white threshold image")?)
+    }
+
+    fn randomThresholdImage(&self, _: JNIEnv, _: JObject, low: jdouble, high: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickRandomThresholdImage(self.wand, low, high) };
+        Ok(utils::magick_result(res, "failed to random threshold image")?)
+    }
+
+    fn orderedDitherImage(&self, env: JNIEnv, _: JObject, threshold_map: JString) -> JNIResult<()> {
+        let threshold_map = CString::new(env.get_jstring(threshold_map)?)?;
+
+        let res = unsafe { bindings::MagickOrderedDitherImage(self.wand, threshold_map.as_ptr()) };
+        Ok(utils::magick_result(res, "failed to ordered dither image")?)
+    }
+
+    fn magickAutoThresholdImage(&self, _: JNIEnv, _: JObject, method: jint) -> JNIResult<()> {
+        let method = AutoThresholdMethod::try_from_int(method)?;
+
+        let res = unsafe { bindings::MagickAutoThresholdImage(self.wand, method) };
+        Ok(utils::magick_result(res, "failed to auto threshold image")?)
+    }
+
+    fn magickAddNoiseImage(&self, _: JNIEnv, _: JObject, noise_type: jint, attenuate: jdouble) -> JNIResult<()> {
+        let noise_type = NoiseType::try_from_int(noise_type)?;
+
+        let res = unsafe { bindings::MagickAddNoiseImage(self.wand, noise_type, attenuate) };
+        Ok(utils::magick_result(res, "failed to add noise to image")?)
+    }
+
+    fn despeckleImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickDespeckleImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to despeckle image")?)
+    }
+
+    fn enhanceImage(&self) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickEnhanceImage(self.wand) };
+        Ok(utils::magick_result(res, "failed to enhance image")?)
+    }
+
+    fn waveletDenoiseImage(&self, _: JNIEnv, _: JObject, threshold: jdouble, softness: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickWaveletDenoiseImage(self.wand, threshold, softness) };
+        Ok(utils::magick_result(res, "failed to wavelet denoise image")?)
+    }
+
+    fn magickStatisticImage(&self, _: JNIEnv, _: JObject, statistic_type: jint, width: jlong, height: jlong) -> JNIResult<()> {
+        let statistic_type = StatisticType::try_from_int(statistic_type)?;
+        let width = usize::try_from(width)?;
+        let height = usize::try_from(height)?;
+
+        let res = unsafe { bindings::MagickStatisticImage(self.wand, statistic_type, width, height) };
+        Ok(utils::magick_result(res, "failed to statistic image")?)
+    }
+
+    fn vignetteImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble, x: jlong, y: jlong) -> JNIResult<()> {
+        let x = isize::try_from(x)?;
+        let y = isize::try_from(y)?;
+
+        let res = unsafe { bindings::MagickVignetteImage(self.wand, radius, sigma, x, y) };
+        Ok(utils::magick_result(res, "failed to vignette image")?)
+    }
+
+    fn magickSwirlImage(&self, _: JNIEnv, _: JObject, degrees: jdouble, method: jint) -> JNIResult<()> {
+        #[cfg(target_os="android")]
+        let method = u32::try_from(method)?;
+
+        let res = unsafe { bindings::MagickSwirlImage(self.wand, degrees, method) };
+        Ok(utils::magick_result(res, "failed to swirl image")?)
+    }
+
+    fn magickWaveImage(&self, _: JNIEnv, _: JObject, amplitude: jdouble, wave_length: jdouble, method: jint) -> JNIResult<()> {
+        #[cfg(target_os="android")]
+        let method = u32::try_from(method)?;
+
+        let res = unsafe { bindings::MagickWaveImage(self.wand, amplitude, wave_length, method) };
+        Ok(utils::magick_result(res, "failed to wave image")?)
+    }
+
+    fn oilPaintImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickOilPaintImage(self.wand, radius, sigma) };
+        Ok(utils::magick_result(res, "failed to oil paint image")?)
+    }
+
+    fn charcoalImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickCharcoalImage(self.wand, radius, sigma) };
+        Ok(utils::magick_result(res, "failed to charcoal image")?)
+    }
+
+    fn sketchImage(&self, _: JNIEnv, _: JObject, radius: jdouble, sigma: jdouble, angle: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickSketchImage(self.wand, radius, sigma, angle) };
+        Ok(utils::magick_result(res, "failed to sketch image")?)
+    }
+
+    fn magickPolaroidImage(
+        &self,
+        env: JNIEnv,
+        _: JObject,
+        drawing_wand: JObject,
+        caption: JString,
+        angle: jdouble,
+        method: jint
+    ) -> JNIResult<()> {
+        let drawing_wand = env.get_handle::<DrawingWand>(drawing_wand)?;
+        let caption = CString::new(env.get_jstring(caption)?)?;
+
+        #[cfg(target_os="android")]
+        let method = u32::try_from(method)?;
+
+        let res = unsafe {
+            bindings::MagickPolaroidImage(self.wand, drawing_wand.wand, caption.as_ptr(), angle, method)
+        };
+        Ok(utils::magick_result(res, "failed to polaroid image")?)
+    }
+
+    fn magickSpreadImage(&self, _: JNIEnv, _: JObject, method: jint, radius: jdouble) -> JNIResult<()> {
+        #[cfg(target_os="android")]
+        let method = u32::try_from(method)?;
+
+        let res = unsafe { bindings::MagickSpreadImage(self.wand, method, radius) };
+        Ok(utils::magick_result(res, "failed to spread image")?)
+    }
+
+    fn solarizeImage(&self, _: JNIEnv, _: JObject, threshold: jdouble) -> JNIResult<()> {
+        let res = unsafe { bindings::MagickSolarizeImage(self.wand, threshold) };
+        Ok(utils::magick_result(res, "failed to solarize image")?)
+    }
+
     fn fit(&self, _: JNIEnv, _: JObject, width: jlong, height: jlong) -> JNIResult<()> {
         let width = usize::try_from(width)?;
         let height = usize::try_from(height)?;
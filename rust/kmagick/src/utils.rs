@@ -1,4 +1,10 @@
 use std::error::Error as StdError;
+use std::ffi::CStr;
+
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::{jboolean, jdoubleArray};
+use magick_rust::bindings;
 
 use crate::errors::JNIError;
 
@@ -16,6 +22,77 @@ pub fn runtime_exception<T, S>(string: S) -> Result<T>
     )
 }
 
+// for raw bindings calls which report success with a MagickBooleanType
+pub fn magick_result<S>(res: bindings::MagickBooleanType, msg: S) -> Result<()>
+    where S: AsRef<str> + ToOwned
+{
+    match res {
+        bindings::MagickBooleanType_MagickTrue => Ok(()),
+        _ => runtime_exception(msg)
+    }
+}
+
+// for raw bindings calls which hand back a brand new wand (or null on failure)
+pub fn new_wand<S>(wand: *mut bindings::MagickWand, msg: S) -> Result<magick_rust::MagickWand>
+    where S: AsRef<str> + ToOwned
+{
+    if wand.is_null() {
+        return runtime_exception(msg);
+    }
+
+    Ok(magick_rust::MagickWand { wand })
+}
+
+// reads and destroys an ExceptionInfo that a raw MagickCore call filled in.
+// if it holds an error, msg is returned as an exception with imagemagick's reason appended
+pub unsafe fn take_exception<S>(exception: *mut bindings::ExceptionInfo, msg: S) -> Result<()>
+    where S: AsRef<str> + ToOwned
+{
+    let res = if (*exception).severity >= bindings::ExceptionType_ErrorException {
+        let mut reason = String::new();
+        for ptr in [(*exception).reason, (*exception).description] {
+            if !ptr.is_null() {
+                reason.push_str(&format!(" {}", CStr::from_ptr(ptr).to_string_lossy()));
+            }
+        }
+
+        runtime_exception(format!("{}:{reason}", msg.as_ref()))
+    } else {
+        Ok(())
+    };
+
+    bindings::DestroyExceptionInfo(exception);
+    res
+}
+
+pub fn to_magick_bool(val: jboolean) -> bindings::MagickBooleanType {
+    match val {
+        0 => bindings::MagickBooleanType_MagickFalse,
+        _ => bindings::MagickBooleanType_MagickTrue
+    }
+}
+
+// copy a whole DoubleArray out of java
+pub fn get_double_array(env: JNIEnv, arr: jdoubleArray) -> Result<Vec<f64>> {
+    let len = env.get_array_length(arr)?;
+    let mut buf = vec![0f64; len as usize];
+    env.get_double_array_region(arr, 0, &mut buf)?;
+
+    Ok(buf)
+}
+
+// runs f inside its own jni local frame, so every local reference made in it is freed afterwards.
+// for loops that create java objects per element, which would otherwise overflow the local reference table
+pub fn with_local_frame<F>(env: JNIEnv, capacity: i32, f: F) -> Result<()>
+    where F: FnOnce() -> Result<()>
+{
+    env.push_local_frame(capacity)?;
+    let res = f();
+    env.pop_local_frame(JObject::null())?;
+
+    res
+}
+
 pub trait EnumIntConversion {
     type Output;
 